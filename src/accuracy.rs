@@ -0,0 +1,304 @@
+//! Empirical accuracy of the Mach number relations
+//!
+//! Each relation with an inverse is sampled over a Mach number range to
+//! measure the round-trip error of the inverse and the deviation of the `f32`
+//! evaluation from the `f64` one. The results form a manifest that can be
+//! written out as CSV and cited in verification documents.
+//!
+//! The manifest covers the relations whose inverse returns a Mach number: the
+//! isentropic, normal shock and pitot relations, the critical and local
+//! pressure coefficients, and the oblique shock relations at a fixed
+//! deflection or wave angle. Inverses that return an angle, such as
+//! `oblique_theta_from_p2_p1` and `expansion_theta_from_p2_p1`, and the
+//! thermally perfect relations, which depend on a [`Gas`](crate::Gas) rather
+//! than a specific heat ratio, are not measured.
+use crate::{
+    cp_from_local_mach, cp_star, impact_pressure_ratio, local_mach_from_cp, mach_from_a_ac,
+    mach_from_cp_star, mach_from_crocco, mach_from_impact_pressure_ratio, mach_from_mach_angle,
+    mach_from_mach_star, mach_from_normal_p02_p01, mach_from_normal_p2_p1,
+    mach_from_normal_rho2_rho1, mach_from_normal_t2_t1, mach_from_p_p0, mach_from_pitot,
+    mach_from_pitot_p02_p1, mach_from_pm_angle, mach_from_rho_rho0, mach_from_t_t0, mach_to_a_ac,
+    mach_to_crocco, mach_to_mach_angle, mach_to_mach_star, mach_to_p_p0, mach_to_pitot_p02_p1,
+    mach_to_pm_angle, mach_to_rho_rho0, mach_to_t_t0, normal_p02_p01, normal_p2_p1,
+    normal_rho2_rho1, normal_t2_t1, oblique_beta, oblique_mach_from_beta_theta, oblique_theta,
+};
+use std::fmt::Write;
+
+/// Measured accuracy of one relation over a Mach number range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccuracyRecord {
+    /// Name of the forward relation.
+    pub relation: &'static str,
+    /// Name of its inverse.
+    pub inverse: &'static str,
+    /// Smallest Mach number sampled.
+    pub mach_min: f64,
+    /// Largest Mach number sampled.
+    pub mach_max: f64,
+    /// Largest relative error in Mach number after a forward and inverse
+    /// evaluation in `f64`.
+    pub round_trip_error: f64,
+    /// Largest deviation of the `f32` forward relation from the `f64` one, in
+    /// units in the last place of the `f32` result. Includes the rounding of
+    /// the inputs to `f32`.
+    pub f32_ulps: f64,
+}
+
+/// Forward relation, its inverse and the Mach number range to sample.
+struct Relation {
+    relation: &'static str,
+    inverse: &'static str,
+    range: (f64, f64),
+    forward64: fn(f64, f64) -> f64,
+    forward32: fn(f32, f32) -> f32,
+    inverse64: fn(f64, f64) -> f64,
+}
+
+fn relations() -> Vec<Relation> {
+    vec![
+        Relation {
+            relation: "mach_to_t_t0",
+            inverse: "mach_from_t_t0",
+            range: (0.05, 5.0),
+            forward64: mach_to_t_t0,
+            forward32: mach_to_t_t0,
+            inverse64: mach_from_t_t0,
+        },
+        Relation {
+            relation: "mach_to_p_p0",
+            inverse: "mach_from_p_p0",
+            range: (0.05, 5.0),
+            forward64: mach_to_p_p0,
+            forward32: mach_to_p_p0,
+            inverse64: mach_from_p_p0,
+        },
+        Relation {
+            relation: "mach_to_rho_rho0",
+            inverse: "mach_from_rho_rho0",
+            range: (0.05, 5.0),
+            forward64: mach_to_rho_rho0,
+            forward32: mach_to_rho_rho0,
+            inverse64: mach_from_rho_rho0,
+        },
+        Relation {
+            relation: "mach_to_a_ac",
+            inverse: "mach_from_a_ac (subsonic)",
+            range: (0.05, 0.95),
+            forward64: mach_to_a_ac,
+            forward32: mach_to_a_ac,
+            inverse64: |a_ac, gamma| mach_from_a_ac(a_ac, gamma, false),
+        },
+        Relation {
+            relation: "mach_to_a_ac",
+            inverse: "mach_from_a_ac (supersonic)",
+            range: (1.05, 5.0),
+            forward64: mach_to_a_ac,
+            forward32: mach_to_a_ac,
+            inverse64: |a_ac, gamma| mach_from_a_ac(a_ac, gamma, true),
+        },
+        Relation {
+            relation: "mach_to_pm_angle",
+            inverse: "mach_from_pm_angle",
+            range: (1.05, 5.0),
+            forward64: mach_to_pm_angle,
+            forward32: mach_to_pm_angle,
+            inverse64: mach_from_pm_angle,
+        },
+        Relation {
+            relation: "mach_to_mach_angle",
+            inverse: "mach_from_mach_angle",
+            range: (1.05, 5.0),
+            forward64: |mach, _| mach_to_mach_angle(mach),
+            forward32: |mach, _| mach_to_mach_angle(mach),
+            inverse64: |mu, _| mach_from_mach_angle(mu),
+        },
+        Relation {
+            relation: "mach_to_mach_star",
+            inverse: "mach_from_mach_star",
+            range: (0.05, 5.0),
+            forward64: mach_to_mach_star,
+            forward32: mach_to_mach_star,
+            inverse64: mach_from_mach_star,
+        },
+        Relation {
+            relation: "mach_to_crocco",
+            inverse: "mach_from_crocco",
+            range: (0.05, 5.0),
+            forward64: mach_to_crocco,
+            forward32: mach_to_crocco,
+            inverse64: mach_from_crocco,
+        },
+        Relation {
+            relation: "mach_to_pitot_p02_p1",
+            inverse: "mach_from_pitot_p02_p1",
+            range: (1.05, 5.0),
+            forward64: mach_to_pitot_p02_p1,
+            forward32: mach_to_pitot_p02_p1,
+            inverse64: mach_from_pitot_p02_p1,
+        },
+        Relation {
+            relation: "normal_p2_p1",
+            inverse: "mach_from_normal_p2_p1",
+            range: (1.05, 5.0),
+            forward64: normal_p2_p1,
+            forward32: normal_p2_p1,
+            inverse64: mach_from_normal_p2_p1,
+        },
+        Relation {
+            relation: "normal_rho2_rho1",
+            inverse: "mach_from_normal_rho2_rho1",
+            range: (1.05, 5.0),
+            forward64: normal_rho2_rho1,
+            forward32: normal_rho2_rho1,
+            inverse64: mach_from_normal_rho2_rho1,
+        },
+        Relation {
+            relation: "normal_t2_t1",
+            inverse: "mach_from_normal_t2_t1",
+            range: (1.05, 5.0),
+            forward64: normal_t2_t1,
+            forward32: normal_t2_t1,
+            inverse64: mach_from_normal_t2_t1,
+        },
+        Relation {
+            relation: "normal_p02_p01",
+            inverse: "mach_from_normal_p02_p01",
+            range: (1.05, 5.0),
+            forward64: normal_p02_p01,
+            forward32: normal_p02_p01,
+            inverse64: mach_from_normal_p02_p01,
+        },
+        Relation {
+            relation: "mach_to_p_p0 (pitot, subsonic)",
+            inverse: "mach_from_pitot",
+            range: (0.05, 0.95),
+            forward64: |mach, gamma| mach_to_p_p0(mach, gamma).recip(),
+            forward32: |mach, gamma| mach_to_p_p0(mach, gamma).recip(),
+            inverse64: mach_from_pitot,
+        },
+        Relation {
+            relation: "mach_to_pitot_p02_p1",
+            inverse: "mach_from_pitot",
+            range: (1.05, 5.0),
+            forward64: mach_to_pitot_p02_p1,
+            forward32: mach_to_pitot_p02_p1,
+            inverse64: mach_from_pitot,
+        },
+        Relation {
+            relation: "impact_pressure_ratio",
+            inverse: "mach_from_impact_pressure_ratio",
+            range: (0.05, 5.0),
+            forward64: impact_pressure_ratio,
+            forward32: impact_pressure_ratio,
+            inverse64: mach_from_impact_pressure_ratio,
+        },
+        Relation {
+            relation: "cp_star",
+            inverse: "mach_from_cp_star",
+            range: (0.3, 0.95),
+            forward64: cp_star,
+            forward32: cp_star,
+            inverse64: mach_from_cp_star,
+        },
+        Relation {
+            relation: "cp_from_local_mach (m_inf = 0.5)",
+            inverse: "local_mach_from_cp",
+            range: (0.05, 2.0),
+            forward64: |mach, gamma| cp_from_local_mach(mach, 0.5, gamma),
+            forward32: |mach, gamma| cp_from_local_mach(mach, 0.5, gamma),
+            inverse64: |cp, gamma| local_mach_from_cp(cp, 0.5, gamma),
+        },
+        Relation {
+            relation: "oblique_beta (theta = 10 deg)",
+            inverse: "oblique_mach_from_beta_theta",
+            range: (1.5, 5.0),
+            forward64: |mach, gamma| oblique_beta(mach, gamma, 10_f64.to_radians()),
+            forward32: |mach, gamma| oblique_beta(mach, gamma, 10_f32.to_radians()),
+            inverse64: |beta, gamma| oblique_mach_from_beta_theta(beta, 10_f64.to_radians(), gamma),
+        },
+        Relation {
+            relation: "oblique_theta (beta = 40 deg)",
+            inverse: "oblique_mach_from_beta_theta",
+            range: (1.6, 5.0),
+            forward64: |mach, gamma| oblique_theta(mach, gamma, 40_f64.to_radians()),
+            forward32: |mach, gamma| oblique_theta(mach, gamma, 40_f32.to_radians()),
+            inverse64: |theta, gamma| {
+                oblique_mach_from_beta_theta(40_f64.to_radians(), theta, gamma)
+            },
+        },
+    ]
+}
+
+/// Measure the accuracy of every relation in the manifest
+///
+/// Each relation is sampled at `samples` evenly spaced Mach numbers across
+/// its range for the specific heat ratio `gamma`.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::accuracy_manifest;
+///
+/// let manifest = accuracy_manifest(1.4, 50);
+/// let t_t0 = manifest.iter().find(|r| r.relation == "mach_to_t_t0").unwrap();
+/// assert!(t_t0.round_trip_error < 1e-12);
+/// assert!(t_t0.f32_ulps < 5.0);
+/// assert!(manifest.iter().all(|r| r.round_trip_error < 1e-6));
+/// ```
+pub fn accuracy_manifest(gamma: f64, samples: usize) -> Vec<AccuracyRecord> {
+    let steps = samples.max(2) - 1;
+    relations()
+        .into_iter()
+        .map(|r| {
+            let (lo, hi) = r.range;
+            let mut round_trip_error = 0.0_f64;
+            let mut f32_ulps = 0.0_f64;
+            for i in 0..=steps {
+                let mach = lo + (hi - lo) * i as f64 / steps as f64;
+                let value = (r.forward64)(mach, gamma);
+                let back = (r.inverse64)(value, gamma);
+                round_trip_error = round_trip_error.max(((back - mach) / mach).abs());
+                let single = (r.forward32)(mach as f32, gamma as f32);
+                f32_ulps = f32_ulps.max((single as f64 - value).abs() / ulp(value as f32));
+            }
+            AccuracyRecord {
+                relation: r.relation,
+                inverse: r.inverse,
+                mach_min: lo,
+                mach_max: hi,
+                round_trip_error,
+                f32_ulps,
+            }
+        })
+        .collect()
+}
+
+/// Spacing between an `f32` value and the next larger magnitude.
+fn ulp(x: f32) -> f64 {
+    let x = x.abs();
+    (f32::from_bits(x.to_bits() + 1) - x) as f64
+}
+
+/// Accuracy manifest as CSV with a header row
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{accuracy_manifest, manifest_csv};
+///
+/// let csv = manifest_csv(&accuracy_manifest(1.4, 10));
+/// assert!(csv.starts_with("relation,inverse,mach_min,mach_max,round_trip_error,f32_ulps\n"));
+/// assert_eq!(csv.lines().count(), 22);
+/// ```
+pub fn manifest_csv(records: &[AccuracyRecord]) -> String {
+    let mut csv = String::from("relation,inverse,mach_min,mach_max,round_trip_error,f32_ulps\n");
+    for r in records {
+        writeln!(
+            csv,
+            "{},{},{},{},{:e},{}",
+            r.relation, r.inverse, r.mach_min, r.mach_max, r.round_trip_error, r.f32_ulps
+        )
+        .unwrap();
+    }
+    csv
+}
@@ -0,0 +1,211 @@
+//! Linear acoustic perturbation relations
+//!
+//! Small-amplitude plane waves in a calorically perfect gas at rest. The
+//! perturbations are isentropic, so pressure, density, temperature and
+//! velocity perturbations are all proportional to one another. Relations are
+//! for a wave travelling in the positive direction; for a wave travelling the
+//! other way the velocity perturbation changes sign.
+use crate::validate;
+use num::Float;
+
+/// Characteristic acoustic impedance rho a for a given pressure and density
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::acoustic_impedance;
+///
+/// assert_eq!(acoustic_impedance(1.4_f64, 101_325.0, 1.225), 416.8601384157521);
+/// ```
+pub fn acoustic_impedance<F: Float>(gamma: F, p: F, rho: F) -> F {
+    validate::gamma(gamma);
+    (gamma * p * rho).sqrt()
+}
+
+/// Pressure perturbation for a given velocity perturbation, p' = rho a u'
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::acoustic_p_from_u;
+///
+/// assert_eq!(acoustic_p_from_u(0.01_f64, 1.225, 340.0), 4.165000000000001);
+/// ```
+pub fn acoustic_p_from_u<F: Float>(u_prime: F, rho: F, a: F) -> F {
+    rho * a * u_prime
+}
+
+/// Velocity perturbation for a given pressure perturbation, u' = p' / (rho a)
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::acoustic_u_from_p;
+///
+/// assert_eq!(acoustic_u_from_p(4.165_f64, 1.225, 340.0), 0.009999999999999998);
+/// ```
+pub fn acoustic_u_from_p<F: Float>(p_prime: F, rho: F, a: F) -> F {
+    p_prime / (rho * a)
+}
+
+/// Density perturbation for a given pressure perturbation, rho' = p' / a^2
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::acoustic_rho_from_p;
+///
+/// assert_eq!(acoustic_rho_from_p(100.0_f64, 340.0), 0.0008650519031141869);
+/// ```
+pub fn acoustic_rho_from_p<F: Float>(p_prime: F, a: F) -> F {
+    p_prime / a.powi(2)
+}
+
+/// Relative temperature perturbation T'/T for a given relative pressure
+/// perturbation p'/p
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::acoustic_t_from_p;
+///
+/// assert_eq!(acoustic_t_from_p(0.01_f64, 1.4), 0.0028571428571428563);
+/// ```
+pub fn acoustic_t_from_p<F: Float>(p_prime_p: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    (gamma - F::one()) / gamma * p_prime_p
+}
+
+/// Pressure reflection coefficient at a junction between two ducts
+///
+/// The junction may be an area change, an interface between two gases, or
+/// both. Each side is described by its area and characteristic impedance rho
+/// a. The junction is assumed compact relative to the wavelength and the mean
+/// flow negligible. The incident wave travels from side 1 towards side 2.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::acoustic_reflection;
+///
+/// // Area contraction in a single gas.
+/// assert_eq!(acoustic_reflection(2.0_f64, 415.0, 1.0, 415.0), 0.3333333333333333);
+/// // Air to helium interface in a constant-area duct.
+/// assert_eq!(acoustic_reflection(1.0_f64, 415.0, 1.0, 164.0), -0.43350604490500866);
+/// ```
+pub fn acoustic_reflection<F: Float>(area1: F, z1: F, area2: F, z2: F) -> F {
+    let y1 = area1 / z1;
+    let y2 = area2 / z2;
+    (y1 - y2) / (y1 + y2)
+}
+
+/// Pressure transmission coefficient at a junction between two ducts
+///
+/// See [`acoustic_reflection`] for the assumptions. The transmitted pressure
+/// amplitude is one plus the reflection coefficient.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::acoustic_transmission;
+///
+/// assert_eq!(acoustic_transmission(2.0_f64, 415.0, 1.0, 415.0), 1.3333333333333333);
+/// ```
+pub fn acoustic_transmission<F: Float>(area1: F, z1: F, area2: F, z2: F) -> F {
+    let y1 = area1 / z1;
+    let y2 = area2 / z2;
+    F::from(2.0).unwrap() * y1 / (y1 + y2)
+}
+
+/// End conditions of a duct for longitudinal acoustic modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuctEnds {
+    /// Both ends open (pressure nodes), half-wave modes.
+    OpenOpen,
+    /// Both ends closed (velocity nodes), half-wave modes.
+    ClosedClosed,
+    /// One end open and one closed, quarter-wave modes.
+    OpenClosed,
+}
+
+/// Frequency of the `n`th longitudinal acoustic mode of a duct
+///
+/// Modes are numbered from 1. A uniform mean flow at Mach number `mach` slows
+/// the round trip of a wave and lowers every frequency by a factor 1 - M^2.
+/// End corrections for open ends should be included in `length`.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{duct_mode_frequency, DuctEnds};
+///
+/// assert_eq!(duct_mode_frequency(1, 1.0_f64, 340.0, 0.0, DuctEnds::OpenOpen), 170.0);
+/// assert_eq!(duct_mode_frequency(1, 1.0_f64, 340.0, 0.0, DuctEnds::OpenClosed), 85.0);
+/// assert_eq!(duct_mode_frequency(2, 1.0_f64, 340.0, 0.2, DuctEnds::OpenClosed), 244.79999999999998);
+/// ```
+pub fn duct_mode_frequency<F: Float>(n: u32, length: F, a: F, mach: F, ends: DuctEnds) -> F {
+    let n = F::from(n).unwrap();
+    let two = F::from(2.0).unwrap();
+    let correction = F::one() - mach.powi(2);
+    match ends {
+        DuctEnds::OpenOpen | DuctEnds::ClosedClosed => n * a * correction / (two * length),
+        DuctEnds::OpenClosed => (two * n - F::one()) * a * correction / (two * two * length),
+    }
+}
+
+/// Resonant frequency of a Helmholtz resonator
+///
+/// `neck_length` should include end corrections, typically about 0.85 neck
+/// radii for each flanged end.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::helmholtz_frequency;
+///
+/// assert_eq!(helmholtz_frequency(340.0_f64, 1e-4, 0.02, 1e-3), 120.99963238092012);
+/// ```
+pub fn helmholtz_frequency<F: Float>(a: F, neck_area: F, neck_length: F, volume: F) -> F {
+    let tau = F::from(std::f64::consts::TAU).unwrap();
+    a / tau * (neck_area / (volume * neck_length)).sqrt()
+}
+
+/// Specific acoustic admittance of a short choked nozzle
+///
+/// For a nozzle that is compact relative to the wavelength, choking fixes the
+/// Mach number at its entrance, which for isentropic perturbations gives the
+/// admittance rho a u' / p' = (gamma - 1) / 2 * M (Marble and Candel). `mach`
+/// is the chamber Mach number at the nozzle entrance. Entropy waves, which
+/// also generate sound at a choked nozzle, are neglected.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::nozzle_admittance;
+///
+/// assert_eq!(nozzle_admittance(0.2_f64, 1.2), 0.019999999999999997);
+/// ```
+pub fn nozzle_admittance<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    (gamma - F::one()) / F::from(2.0).unwrap() * mach
+}
+
+/// Pressure reflection coefficient of a short choked nozzle
+///
+/// Ratio of the reflected to the incident pressure wave amplitude at the
+/// nozzle entrance, (1 - Y) / (1 + Y) for the admittance Y of
+/// [`nozzle_admittance`]. Close to one, so a choked nozzle behaves almost as a
+/// closed end in low-Mach chambers.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::nozzle_reflection;
+///
+/// assert_eq!(nozzle_reflection(0.2_f64, 1.2), 0.9607843137254901);
+/// ```
+pub fn nozzle_reflection<F: Float>(mach: F, gamma: F) -> F {
+    let y = nozzle_admittance(mach, gamma);
+    (F::one() - y) / (F::one() + y)
+}
@@ -0,0 +1,205 @@
+//! Conversions between calibrated, equivalent and true airspeed and Mach number
+//!
+//! Calibrated airspeed (CAS) is defined by the impact pressure through the
+//! compressible pitot relations at sea-level standard conditions, equivalent
+//! airspeed (EAS) by the dynamic pressure at sea-level density, and true
+//! airspeed (TAS) is the speed relative to the air. Speeds are in m/s.
+use crate::atmosphere::R_AIR;
+use crate::{impact_pressure, mach_from_impact_pressure_ratio, standard_atmosphere, validate};
+use num::Float;
+
+/// Air data conditions at a pressure altitude
+///
+/// The static pressure is that of the standard atmosphere at the pressure
+/// altitude, while the temperature is offset from standard by `delta_t`.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::AirData;
+///
+/// let air = AirData::new(10000.0_f64, 0.0);
+/// let mach = air.mach_from_cas(150.0);
+/// assert_eq!(mach, 0.8148324990135951);
+/// assert!((air.cas_from_mach(mach) - 150.0).abs() < 1e-9);
+/// assert_eq!(air.tas_from_cas(150.0), 244.0123189972744);
+/// assert_eq!(air.eas_from_tas(air.tas_from_cas(150.0)), 141.63290949496735);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AirData<F> {
+    p: F,
+    t: F,
+    p_sl: F,
+    a_sl: F,
+    rho_sl: F,
+}
+
+impl<F: Float> AirData<F> {
+    /// Conditions at a geopotential pressure altitude with a temperature
+    /// offset from standard.
+    pub fn new(pressure_altitude: F, delta_t: F) -> Self {
+        let state = standard_atmosphere(pressure_altitude);
+        let sea_level = standard_atmosphere(F::zero());
+        Self {
+            p: state.p,
+            t: state.t + delta_t,
+            p_sl: sea_level.p,
+            a_sl: sea_level.a,
+            rho_sl: sea_level.rho,
+        }
+    }
+
+    /// Static pressure.
+    pub fn p(&self) -> F {
+        self.p
+    }
+
+    /// Static temperature.
+    pub fn t(&self) -> F {
+        self.t
+    }
+
+    /// Density.
+    pub fn rho(&self) -> F {
+        self.p / (F::from(R_AIR).unwrap() * self.t)
+    }
+
+    /// Speed of sound.
+    pub fn a(&self) -> F {
+        (gamma::<F>() * F::from(R_AIR).unwrap() * self.t).sqrt()
+    }
+
+    /// Impact pressure for a calibrated airspeed.
+    pub fn impact_pressure_from_cas(&self, cas: F) -> F {
+        impact_pressure(cas / self.a_sl, self.p_sl, gamma())
+    }
+
+    /// Mach number for a calibrated airspeed.
+    pub fn mach_from_cas(&self, cas: F) -> F {
+        let qc = self.impact_pressure_from_cas(cas);
+        mach_from_impact_pressure_ratio(qc / self.p, gamma())
+    }
+
+    /// Calibrated airspeed for a Mach number.
+    pub fn cas_from_mach(&self, mach: F) -> F {
+        validate::mach(mach);
+        let qc = impact_pressure(mach, self.p, gamma());
+        self.a_sl * mach_from_impact_pressure_ratio(qc / self.p_sl, gamma())
+    }
+
+    /// True airspeed for a Mach number.
+    pub fn tas_from_mach(&self, mach: F) -> F {
+        validate::mach(mach);
+        mach * self.a()
+    }
+
+    /// Mach number for a true airspeed.
+    pub fn mach_from_tas(&self, tas: F) -> F {
+        tas / self.a()
+    }
+
+    /// True airspeed for a calibrated airspeed.
+    pub fn tas_from_cas(&self, cas: F) -> F {
+        self.tas_from_mach(self.mach_from_cas(cas))
+    }
+
+    /// Calibrated airspeed for a true airspeed.
+    pub fn cas_from_tas(&self, tas: F) -> F {
+        self.cas_from_mach(self.mach_from_tas(tas))
+    }
+
+    /// Equivalent airspeed for a true airspeed.
+    pub fn eas_from_tas(&self, tas: F) -> F {
+        tas * (self.rho() / self.rho_sl).sqrt()
+    }
+
+    /// True airspeed for an equivalent airspeed.
+    pub fn tas_from_eas(&self, eas: F) -> F {
+        eas / (self.rho() / self.rho_sl).sqrt()
+    }
+}
+
+/// Air data sensitivities and uncertainties at one envelope point
+///
+/// Returned by [`airdata_uncertainty_budget`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AirDataBudget<F> {
+    /// Pressure altitude.
+    pub pressure_altitude: F,
+    /// Mach number.
+    pub mach: F,
+    /// Static pressure.
+    pub p: F,
+    /// Impact pressure.
+    pub qc: F,
+    /// Sensitivity of Mach number to static pressure, dM/dp.
+    pub dmach_dp: F,
+    /// Sensitivity of Mach number to impact pressure, dM/dqc.
+    pub dmach_dqc: F,
+    /// Sensitivity of calibrated airspeed to impact pressure, dCAS/dqc.
+    pub dcas_dqc: F,
+    /// Mach number uncertainty from the pressure uncertainties.
+    pub mach_uncertainty: F,
+    /// Calibrated airspeed uncertainty from the impact pressure uncertainty.
+    pub cas_uncertainty: F,
+}
+
+/// Uncertainty budget of Mach number and calibrated airspeed across a flight
+/// envelope
+///
+/// Each envelope point is a `(pressure_altitude, mach)` pair. The static and
+/// impact pressure uncertainties are taken as independent and propagated
+/// with sensitivities from central differences.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::airdata_uncertainty_budget;
+///
+/// let envelope = [(0.0_f64, 0.3), (10000.0, 0.8), (15000.0, 1.5)];
+/// let budget = airdata_uncertainty_budget(&envelope, 50.0, 50.0);
+/// assert!((budget[1].mach - 0.8).abs() < 1e-12);
+/// assert_eq!(budget[0].mach_uncertainty, 0.0011259892018607642);
+/// assert_eq!(budget[2].mach_uncertainty, 0.0028690719547615614);
+/// assert!(budget[0].dmach_dp < 0.0);
+/// ```
+pub fn airdata_uncertainty_budget<F: Float>(
+    envelope: &[(F, F)],
+    p_uncertainty: F,
+    qc_uncertainty: F,
+) -> Vec<AirDataBudget<F>> {
+    let two = F::from(2.).unwrap();
+    let step = F::epsilon().cbrt();
+    let sea_level = standard_atmosphere(F::zero());
+    let mach = |p: F, qc: F| mach_from_impact_pressure_ratio(qc / p, gamma());
+    let cas = |qc: F| sea_level.a * mach(sea_level.p, qc);
+    envelope
+        .iter()
+        .map(|&(pressure_altitude, m)| {
+            let p = standard_atmosphere(pressure_altitude).p;
+            let qc = impact_pressure(m, p, gamma());
+            let (hp, hq) = (step * p, step * qc);
+            let dmach_dp = (mach(p + hp, qc) - mach(p - hp, qc)) / (two * hp);
+            let dmach_dqc = (mach(p, qc + hq) - mach(p, qc - hq)) / (two * hq);
+            let dcas_dqc = (cas(qc + hq) - cas(qc - hq)) / (two * hq);
+            AirDataBudget {
+                pressure_altitude,
+                mach: mach(p, qc),
+                p,
+                qc,
+                dmach_dp,
+                dmach_dqc,
+                dcas_dqc,
+                mach_uncertainty: ((dmach_dp * p_uncertainty).powi(2)
+                    + (dmach_dqc * qc_uncertainty).powi(2))
+                .sqrt(),
+                cas_uncertainty: (dcas_dqc * qc_uncertainty).abs(),
+            }
+        })
+        .collect()
+}
+
+/// Specific heat ratio of air used by the air data standards.
+fn gamma<F: Float>() -> F {
+    F::from(1.4).unwrap()
+}
@@ -0,0 +1,139 @@
+//! International Standard Atmosphere
+//!
+//! Identical to the US Standard Atmosphere 1976 up to 86 km geometric
+//! altitude. Altitudes are geopotential, in metres, and results are in SI
+//! units. Below sea level the first layer is extended and above the
+//! mesopause the last.
+use num::Float;
+
+/// Sea-level standard gravity.
+const G0: f64 = 9.80665;
+/// Specific gas constant of air used by the standard.
+pub(crate) const R_AIR: f64 = 287.05287;
+/// Effective Earth radius used for geopotential altitude.
+const EARTH_RADIUS: f64 = 6356766.0;
+/// Base geopotential altitude and temperature lapse rate of each layer.
+const LAYERS: [(f64, f64); 7] = [
+    (0.0, -0.0065),
+    (11000.0, 0.0),
+    (20000.0, 0.001),
+    (32000.0, 0.0028),
+    (47000.0, 0.0),
+    (51000.0, -0.0028),
+    (71000.0, -0.002),
+];
+
+/// Static properties of the standard atmosphere at one altitude
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtmosphereState<F> {
+    /// Static temperature.
+    pub t: F,
+    /// Static pressure.
+    pub p: F,
+    /// Density.
+    pub rho: F,
+    /// Speed of sound.
+    pub a: F,
+}
+
+/// Standard atmosphere at a geopotential altitude
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::standard_atmosphere;
+///
+/// let sea_level = standard_atmosphere(0.0_f64);
+/// assert_eq!(sea_level.t, 288.15);
+/// assert_eq!(sea_level.p, 101325.0);
+/// assert_eq!(sea_level.rho, 1.225000018124288);
+/// assert_eq!(sea_level.a, 340.293988026089);
+///
+/// let cruise = standard_atmosphere(11000.0_f64);
+/// assert_eq!(cruise.t, 216.64999999999998);
+/// assert_eq!(cruise.p, 22632.040095007793);
+/// ```
+pub fn standard_atmosphere<F: Float>(altitude: F) -> AtmosphereState<F> {
+    let n = |x: f64| F::from(x).unwrap();
+    let (g0, r) = (n(G0), n(R_AIR));
+    let mut t = n(288.15);
+    let mut p = n(101325.0);
+    for (i, &(base, lapse)) in LAYERS.iter().enumerate() {
+        let (base, lapse) = (n(base), n(lapse));
+        match LAYERS.get(i + 1) {
+            Some(&(top, _)) if altitude > n(top) => {
+                (t, p) = layer(t, p, lapse, n(top) - base, g0, r);
+            }
+            _ => {
+                let (t, p) = layer(t, p, lapse, altitude - base, g0, r);
+                return AtmosphereState {
+                    t,
+                    p,
+                    rho: p / (r * t),
+                    a: (n(1.4) * r * t).sqrt(),
+                };
+            }
+        }
+    }
+    unreachable!()
+}
+
+/// Pressure altitude, the geopotential altitude at which the standard
+/// atmosphere has a given static pressure
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{pressure_altitude, standard_atmosphere};
+///
+/// assert_eq!(pressure_altitude(101325.0_f64), 0.0);
+/// let p = standard_atmosphere(25000.0_f64).p;
+/// assert!((pressure_altitude(p) - 25000.0).abs() < 1e-6);
+/// ```
+pub fn pressure_altitude<F: Float>(p: F) -> F {
+    let n = |x: f64| F::from(x).unwrap();
+    let (g0, r) = (n(G0), n(R_AIR));
+    let mut t_base = n(288.15);
+    let mut p_base = n(101325.0);
+    for (i, &(base, lapse)) in LAYERS.iter().enumerate() {
+        let (base, lapse) = (n(base), n(lapse));
+        if let Some(&(top, _)) = LAYERS.get(i + 1) {
+            let (t_top, p_top) = layer(t_base, p_base, lapse, n(top) - base, g0, r);
+            if p < p_top {
+                t_base = t_top;
+                p_base = p_top;
+                continue;
+            }
+        }
+        return if lapse == F::zero() {
+            base - r * t_base / g0 * (p / p_base).ln()
+        } else {
+            base + t_base / lapse * ((p / p_base).powf(-lapse * r / g0) - F::one())
+        };
+    }
+    unreachable!()
+}
+
+/// Geopotential altitude from geometric altitude
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::geopotential_altitude;
+///
+/// assert_eq!(geopotential_altitude(11000.0_f64), 10980.99804546838);
+/// ```
+pub fn geopotential_altitude<F: Float>(z: F) -> F {
+    let r0 = F::from(EARTH_RADIUS).unwrap();
+    r0 * z / (r0 + z)
+}
+
+/// Temperature and pressure a height `dh` above the base of a layer.
+fn layer<F: Float>(t: F, p: F, lapse: F, dh: F, g0: F, r: F) -> (F, F) {
+    if lapse == F::zero() {
+        (t, p * (-g0 * dh / (r * t)).exp())
+    } else {
+        let t_new = t + lapse * dh;
+        (t_new, p * (t_new / t).powf(-g0 / (lapse * r)))
+    }
+}
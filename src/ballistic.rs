@@ -0,0 +1,72 @@
+//! Reduction of ballistic range shadowgraphs
+//!
+//! Flight Mach number is inferred from wave angles measured on range
+//! photographs, with the measurement uncertainty of the angles propagated to
+//! the Mach number. Angles are in radians.
+use crate::{mach_from_mach_angle, oblique_mach_from_beta_theta, validate};
+use num::Float;
+
+/// Mach number inferred from a measurement, with its standard uncertainty
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MachEstimate<F> {
+    /// Inferred Mach number.
+    pub mach: F,
+    /// Standard uncertainty of the Mach number.
+    pub uncertainty: F,
+}
+
+/// Mach number from the far-field Mach wave angle
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_from_wave_angle;
+///
+/// let estimate = mach_from_wave_angle(0.5235987755982988_f64, 0.01);
+/// assert_eq!(estimate.mach, 2.0000000000000004);
+/// assert_eq!(estimate.uncertainty, 0.03464101615137755);
+/// ```
+pub fn mach_from_wave_angle<F: Float>(mu: F, mu_uncertainty: F) -> MachEstimate<F> {
+    validate::mach_angle(mu);
+    MachEstimate {
+        mach: mach_from_mach_angle(mu),
+        // dM/dmu = -cos(mu) / sin^2(mu)
+        uncertainty: mu_uncertainty * mu.cos() / mu.sin().powi(2),
+    }
+}
+
+/// Mach number from the attached shock angle on a wedge of known half-angle
+///
+/// The uncertainties of the shock angle and wedge angle are combined as a
+/// root sum of squares of their sensitivities.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{mach_from_wedge_shock, oblique_beta};
+///
+/// let theta = 0.17453292519943295_f64;
+/// let beta = oblique_beta(3.0, 1.4, theta);
+/// let estimate = mach_from_wedge_shock(beta, 0.005, theta, 0.001, 1.4);
+/// assert!((estimate.mach - 3.0).abs() < 1e-9);
+/// assert_eq!(estimate.uncertainty, 0.04057998420521671);
+/// ```
+pub fn mach_from_wedge_shock<F: Float>(
+    beta: F,
+    beta_uncertainty: F,
+    theta: F,
+    theta_uncertainty: F,
+    gamma: F,
+) -> MachEstimate<F> {
+    validate::deflection(theta);
+    validate::gamma(gamma);
+    let mach = |b: F, t: F| oblique_mach_from_beta_theta(b, t, gamma);
+    let h = F::epsilon().sqrt();
+    let two = F::from(2.).unwrap();
+    let dm_dbeta = (mach(beta + h, theta) - mach(beta - h, theta)) / (two * h);
+    let dm_dtheta = (mach(beta, theta + h) - mach(beta, theta - h)) / (two * h);
+    MachEstimate {
+        mach: mach(beta, theta),
+        uncertainty: (dm_dbeta * beta_uncertainty).hypot(dm_dtheta * theta_uncertainty),
+    }
+}
@@ -0,0 +1,134 @@
+//! Characteristic boundary conditions for 1-D and quasi-1-D Euler solvers
+//!
+//! The builders return the ghost state to impose at a boundary, combining the
+//! specified conditions with the Riemann invariant that leaves the domain
+//! through the boundary. The flow is taken to run in the positive direction:
+//! inflow boundaries are on the left of the domain and outflow boundaries on
+//! the right. The invariants assume constant gamma, evaluated from the gas at
+//! the stagnation temperature for inflow and at the interior temperature for
+//! outflow.
+//!
+//! [`freestream_state`] gives the uniform state used to initialise a domain.
+use crate::{validate, Gas, PrimitiveState};
+use num::Float;
+
+/// Ghost state at a subsonic inflow with specified stagnation conditions
+///
+/// The outgoing invariant u - 2a/(gamma - 1) is taken from the interior state
+/// adjacent to the boundary and combined with the stagnation pressure and
+/// temperature. Supersonic inflow has no outgoing invariant, and all
+/// variables should be specified directly instead.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{inflow_ghost_state, mach_to_p_p0, mach_to_t_t0, CaloricallyPerfect, PrimitiveState};
+///
+/// // An interior state already consistent with the reservoir is reproduced.
+/// let air = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// let (p0, t0) = (101325.0, 300.0_f64);
+/// let t = t0 * mach_to_t_t0(0.5, 1.4);
+/// let p = p0 * mach_to_p_p0(0.5, 1.4);
+/// let interior = PrimitiveState::new(p / (287.0 * t), 0.5 * (1.4 * 287.0 * t).sqrt(), p);
+///
+/// let ghost = inflow_ghost_state(&air, interior, p0, t0);
+/// assert!((ghost.u - interior.u).abs() < 1e-9);
+/// assert!((ghost.p - interior.p).abs() < 1e-6);
+/// ```
+pub fn inflow_ghost_state<F: Float, G: Gas<F>>(
+    gas: &G,
+    interior: PrimitiveState<F>,
+    p0: F,
+    t0: F,
+) -> PrimitiveState<F> {
+    let two = F::from(2.).unwrap();
+    let four = F::from(4.).unwrap();
+    let gamma = gas.gamma(t0);
+    let r = gas.r();
+    let gm1 = gamma - F::one();
+    let j = interior.u - two * interior.speed_of_sound(gas) / gm1;
+    let a0_sq = gamma * r * t0;
+
+    // a = (gamma - 1)/2 (u - J) together with a^2 + (gamma - 1)/2 u^2 = a0^2.
+    let qa = gm1.powi(2) / four + gm1 / two;
+    let qb = -gm1.powi(2) / two * j;
+    let qc = gm1.powi(2) / four * j.powi(2) - a0_sq;
+    let u = (-qb + (qb.powi(2) - four * qa * qc).sqrt()) / (two * qa);
+
+    let a = gm1 / two * (u - j);
+    let t = a.powi(2) / (gamma * r);
+    let p = p0 * (t / t0).powf(gamma / gm1);
+    PrimitiveState::new(p / (r * t), u, p)
+}
+
+/// Ghost state at an outflow with specified back pressure
+///
+/// For subsonic outflow the back pressure is imposed while the entropy and
+/// the outgoing invariant u + 2a/(gamma - 1) are taken from the interior.
+/// Supersonic outflow ignores the back pressure and extrapolates the interior
+/// state.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{outflow_ghost_state, CaloricallyPerfect, PrimitiveState};
+///
+/// let air = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// let interior = PrimitiveState::new(1.2, 100.0, 101325.0);
+///
+/// // Raising the back pressure sends a compression wave upstream.
+/// let ghost = outflow_ghost_state(&air, interior, 102000.0);
+/// assert_eq!(ghost.p, 102000.0);
+/// assert!(ghost.u < interior.u);
+///
+/// let supersonic = PrimitiveState::new(1.2, 600.0, 101325.0);
+/// assert_eq!(outflow_ghost_state(&air, supersonic, 102000.0), supersonic);
+/// ```
+pub fn outflow_ghost_state<F: Float, G: Gas<F>>(
+    gas: &G,
+    interior: PrimitiveState<F>,
+    p_back: F,
+) -> PrimitiveState<F> {
+    let a_i = interior.speed_of_sound(gas);
+    if interior.u >= a_i {
+        return interior;
+    }
+    let two = F::from(2.).unwrap();
+    let gamma = gas.gamma(interior.temperature(gas));
+    let gm1 = gamma - F::one();
+    let j = interior.u + two * a_i / gm1;
+    let rho = interior.rho * (p_back / interior.p).powf(F::one() / gamma);
+    let a = (gamma * p_back / rho).sqrt();
+    PrimitiveState::new(rho, j - two * a / gm1, p_back)
+}
+
+/// Conservative 2-D freestream state for initialising a domain
+///
+/// Returns `[rho, rho u, rho v, E]` for a given Mach number, static pressure
+/// and temperature, with the flow inclined at `angle` radians to the x axis.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{freestream_state, CaloricallyPerfect};
+///
+/// let air = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// let [rho, rho_u, rho_v, e] = freestream_state(&air, 2.0, 101325.0, 288.15, 0.0);
+/// assert_eq!(rho, 1.2252256827617731);
+/// assert_eq!(rho_u, 833.7970717822684);
+/// assert_eq!(rho_v, 0.0);
+/// assert_eq!(e, 537022.5);
+/// ```
+pub fn freestream_state<F: Float, G: Gas<F>>(gas: &G, mach: F, p: F, t: F, angle: F) -> [F; 4] {
+    validate::mach(mach);
+    let velocity = mach * gas.speed_of_sound(t);
+    let rho = p / (gas.r() * t);
+    let state = PrimitiveState::new(rho, velocity, p);
+    let [_, _, e] = state.to_conservative(gas);
+    [
+        rho,
+        rho * velocity * angle.cos(),
+        rho * velocity * angle.sin(),
+        e,
+    ]
+}
@@ -0,0 +1,126 @@
+//! Reduction of rocket nozzle test-stand data
+//!
+//! Measured chamber pressure, thrust and mass flow are compared with the
+//! ideal one-dimensional nozzle to give the discharge coefficient and the
+//! thrust coefficient and characteristic velocity efficiencies. Measurement
+//! uncertainties are taken as independent and propagated to first order, so
+//! relative uncertainties add as a root sum of squares.
+use crate::{mach_to_mcpt0_ap0, thrust_coefficient, validate};
+use num::Float;
+
+/// Test-stand measurements of a rocket nozzle, each with its standard
+/// uncertainty
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NozzleTest<F> {
+    /// Chamber stagnation pressure.
+    pub p0: F,
+    /// Standard uncertainty of the chamber pressure.
+    pub p0_uncertainty: F,
+    /// Chamber stagnation temperature, or the theoretical flame temperature
+    /// for a hot firing.
+    pub t0: F,
+    /// Standard uncertainty of the chamber temperature.
+    pub t0_uncertainty: F,
+    /// Measured thrust.
+    pub thrust: F,
+    /// Standard uncertainty of the thrust.
+    pub thrust_uncertainty: F,
+    /// Measured mass flow rate.
+    pub mass_flow: F,
+    /// Standard uncertainty of the mass flow rate.
+    pub mass_flow_uncertainty: F,
+    /// Geometric throat area.
+    pub throat_area: F,
+    /// Standard uncertainty of the throat area.
+    pub throat_area_uncertainty: F,
+    /// Ambient pressure.
+    pub pa: F,
+}
+
+/// Reduced quantity with its standard uncertainty
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reduced<F> {
+    /// Reduced value.
+    pub value: F,
+    /// Standard uncertainty of the value.
+    pub uncertainty: F,
+}
+
+/// Performance figures reduced from a nozzle test
+///
+/// Returned by [`NozzleTest::reduce`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NozzleCalibration<F> {
+    /// Measured over ideal mass flow through the geometric throat.
+    pub discharge_coefficient: Reduced<F>,
+    /// Measured over ideal thrust coefficient.
+    pub thrust_coefficient_efficiency: Reduced<F>,
+    /// Measured over ideal characteristic velocity p0 A* / mdot, the
+    /// reciprocal of the discharge coefficient.
+    pub c_star_efficiency: Reduced<F>,
+}
+
+impl<F: Float> NozzleTest<F> {
+    /// Reduce the measurements for a nozzle with design exit Mach number
+    /// `mach_exit`, specific heat ratio `gamma` and specific gas constant `r`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::NozzleTest;
+    ///
+    /// let test = NozzleTest {
+    ///     p0: 2.0e6_f64,
+    ///     p0_uncertainty: 1.0e4,
+    ///     t0: 290.0,
+    ///     t0_uncertainty: 1.0,
+    ///     thrust: 1050.0,
+    ///     thrust_uncertainty: 5.0,
+    ///     mass_flow: 2.1,
+    ///     mass_flow_uncertainty: 0.01,
+    ///     throat_area: 4.5e-4,
+    ///     throat_area_uncertainty: 1.0e-6,
+    ///     pa: 1.0e5,
+    /// };
+    /// let calibration = test.reduce(2.5, 1.4, 287.0);
+    /// let cd = calibration.discharge_coefficient;
+    /// assert_eq!(cd.value, 0.9830971858366804);
+    /// assert_eq!(cd.uncertainty, 0.007329624792015221);
+    /// assert_eq!(calibration.thrust_coefficient_efficiency.value, 0.8498485048083256);
+    /// assert!((calibration.c_star_efficiency.value * cd.value - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn reduce(&self, mach_exit: F, gamma: F, r: F) -> NozzleCalibration<F> {
+        validate::gamma(gamma);
+        let half = F::from(0.5).unwrap();
+        let cp = gamma * r / (gamma - F::one());
+        let ideal_flow =
+            mach_to_mcpt0_ap0(F::one(), gamma) * self.throat_area * self.p0 / (cp * self.t0).sqrt();
+        let cd = self.mass_flow / ideal_flow;
+        let cf = self.thrust / (self.p0 * self.throat_area);
+        let cf_efficiency = cf / thrust_coefficient(mach_exit, gamma, self.pa / self.p0);
+
+        let u_p0 = self.p0_uncertainty / self.p0;
+        let u_t0 = self.t0_uncertainty / self.t0;
+        let u_thrust = self.thrust_uncertainty / self.thrust;
+        let u_mass_flow = self.mass_flow_uncertainty / self.mass_flow;
+        let u_area = self.throat_area_uncertainty / self.throat_area;
+        let u_cd =
+            (u_mass_flow.powi(2) + u_area.powi(2) + u_p0.powi(2) + (half * u_t0).powi(2)).sqrt();
+        let u_cf = (u_thrust.powi(2) + u_area.powi(2) + u_p0.powi(2)).sqrt();
+
+        NozzleCalibration {
+            discharge_coefficient: Reduced {
+                value: cd,
+                uncertainty: cd * u_cd,
+            },
+            thrust_coefficient_efficiency: Reduced {
+                value: cf_efficiency,
+                uncertainty: cf_efficiency * u_cf,
+            },
+            c_star_efficiency: Reduced {
+                value: cd.recip(),
+                uncertainty: cd.recip() * u_cd,
+            },
+        }
+    }
+}
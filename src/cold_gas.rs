@@ -0,0 +1,117 @@
+//! Cold-gas thruster blowdown performance
+//!
+//! A tank of gas feeds a nozzle, optionally through a pressure regulator. The
+//! gas left in the tank expands isentropically as it empties, the regulator
+//! holds the chamber pressure until the tank falls below its set point, and
+//! the nozzle is always choked with a frozen specific heat ratio evaluated at
+//! the chamber temperature.
+use crate::nozzle::STANDARD_GRAVITY;
+use crate::{mach_to_mcpt0_ap0, thrust_coefficient, validate, Gas};
+use num::Float;
+
+/// Cold-gas thruster geometry
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{gases, ColdGasThruster};
+///
+/// // One litre nitrogen tank at 200 bar regulated to 5 bar.
+/// let thruster = ColdGasThruster {
+///     volume: 1.0e-3_f64,
+///     throat_area: 1.0e-6,
+///     mach_exit: 4.0,
+///     regulator: Some(5.0e5),
+/// };
+/// let history = thruster.blowdown(&gases::nitrogen(), 2.0e7, 293.0, 0.0, 60.0, 1.0);
+/// assert_eq!(history[0].thrust, 0.825957092990295);
+/// assert_eq!(history[0].isp, 72.54580195287326);
+/// assert_eq!(history[0].p0, 5.0e5);
+/// assert!(history[60].tank_temperature < 293.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColdGasThruster<F> {
+    /// Tank volume.
+    pub volume: F,
+    /// Nozzle throat area.
+    pub throat_area: F,
+    /// Nozzle exit Mach number.
+    pub mach_exit: F,
+    /// Regulated chamber pressure, or `None` for an unregulated blowdown.
+    pub regulator: Option<F>,
+}
+
+/// State of a cold-gas thruster at one instant
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColdGasPoint<F> {
+    /// Time since the start of the firing.
+    pub time: F,
+    /// Tank pressure.
+    pub tank_pressure: F,
+    /// Tank temperature, also the chamber stagnation temperature.
+    pub tank_temperature: F,
+    /// Gas mass left in the tank.
+    pub tank_mass: F,
+    /// Chamber stagnation pressure.
+    pub p0: F,
+    /// Mass flow rate.
+    pub mass_flow: F,
+    /// Thrust.
+    pub thrust: F,
+    /// Specific impulse in seconds.
+    pub isp: F,
+}
+
+impl<F: Float> ColdGasThruster<F> {
+    /// Thruster performance during a continuous firing
+    ///
+    /// The tank starts at pressure `p_tank` and temperature `t_tank` and the
+    /// nozzle exhausts into ambient pressure `pa`. The tank mass is integrated
+    /// with explicit time steps of `dt` up to `duration`, stopping early once
+    /// the tank pressure falls to the ambient pressure.
+    pub fn blowdown<G: Gas<F>>(
+        &self,
+        gas: &G,
+        p_tank: F,
+        t_tank: F,
+        pa: F,
+        duration: F,
+        dt: F,
+    ) -> Vec<ColdGasPoint<F>> {
+        validate::supersonic(self.mach_exit);
+        validate::gamma(gas.gamma(t_tank));
+        let r = gas.r();
+        let mut p = p_tank;
+        let mut t = t_tank;
+        let mut mass = p * self.volume / (r * t);
+        let mut time = F::zero();
+        let mut history = Vec::new();
+        while time <= duration && p > pa {
+            let gamma = gas.gamma(t);
+            let p0 = self.regulator.map_or(p, |p_reg| p_reg.min(p));
+            let mass_flow =
+                mach_to_mcpt0_ap0(F::one(), gamma) * self.throat_area * p0 / (gas.cp(t) * t).sqrt();
+            let thrust = thrust_coefficient(self.mach_exit, gamma, pa / p0) * p0 * self.throat_area;
+            history.push(ColdGasPoint {
+                time,
+                tank_pressure: p,
+                tank_temperature: t,
+                tank_mass: mass,
+                p0,
+                mass_flow,
+                thrust,
+                isp: thrust / (mass_flow * F::from(STANDARD_GRAVITY).unwrap()),
+            });
+
+            let remaining = mass - mass_flow * dt;
+            if remaining <= F::zero() || dt.is_nan() || dt <= F::zero() {
+                break;
+            }
+            t = t * (remaining / mass).powf(gamma - F::one());
+            mass = remaining;
+            p = mass * r * t / self.volume;
+            time = time + dt;
+        }
+        history
+    }
+}
@@ -0,0 +1,164 @@
+//! Pressure coefficients in compressible flow
+//!
+//! Subsonic compressibility corrections, the critical pressure coefficient
+//! and the isentropic relation between pressure coefficient and local Mach
+//! number.
+use crate::validate;
+use eqsolver::single_variable::FDNewton;
+use num::Float;
+
+/// Rule mapping an incompressible pressure coefficient to a compressible one
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::CompressibilityCorrection;
+///
+/// let cp0 = -0.5_f64;
+/// assert_eq!(CompressibilityCorrection::PrandtlGlauert.cp(cp0, 0.6, 1.4), -0.625);
+/// assert_eq!(CompressibilityCorrection::KarmanTsien.cp(cp0, 0.6, 1.4), -0.6666666666666666);
+/// assert_eq!(CompressibilityCorrection::Laitone.cp(cp0, 0.6, 1.4), -0.7359434795407712);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressibilityCorrection {
+    /// Prandtl-Glauert, Cp = Cp0 / beta, from linearised theory.
+    PrandtlGlauert,
+    /// Karman-Tsien, based on a tangent-gas approximation; more accurate for
+    /// larger suctions.
+    KarmanTsien,
+    /// Laitone, which accounts for the local rather than freestream Mach
+    /// number.
+    Laitone,
+}
+
+impl CompressibilityCorrection {
+    /// Compressible pressure coefficient for an incompressible value `cp0`
+    /// at freestream Mach number `mach`.
+    pub fn cp<F: Float>(&self, cp0: F, mach: F, gamma: F) -> F {
+        validate::mach(mach);
+        validate::gamma(gamma);
+        let two = F::from(2.).unwrap();
+        let m2 = mach.powi(2);
+        let beta = (F::one() - m2).sqrt();
+        match self {
+            Self::PrandtlGlauert => cp0 / beta,
+            Self::KarmanTsien => cp0 / (beta + m2 / (F::one() + beta) * cp0 / two),
+            Self::Laitone => {
+                cp0 / (beta + m2 * (F::one() + (gamma - F::one()) / two * m2) / (two * beta) * cp0)
+            }
+        }
+    }
+}
+
+/// Critical Mach number, at which the point of minimum pressure first
+/// becomes sonic
+///
+/// `cp0_min` is the minimum incompressible pressure coefficient on the body.
+/// The compressible value from `correction` is matched to the critical
+/// pressure coefficient.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{critical_mach, CompressibilityCorrection};
+///
+/// let mach = critical_mach(-0.43_f64, 1.4, CompressibilityCorrection::PrandtlGlauert);
+/// assert_eq!(mach, 0.7371059142269233);
+/// ```
+pub fn critical_mach<F: Float>(cp0_min: F, gamma: F, correction: CompressibilityCorrection) -> F {
+    validate::gamma(gamma);
+    let f = |mach: F| correction.cp(cp0_min, mach, gamma) - critical_cp(mach, gamma);
+    FDNewton::new(f)
+        .solve(F::from(0.5).unwrap())
+        .unwrap_or(F::nan())
+}
+
+/// Critical pressure coefficient Cp*, at which the local flow is sonic
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::cp_star;
+///
+/// assert_eq!(cp_star(0.5_f64, 1.4), -2.133402668349714);
+/// assert_eq!(cp_star(1.0_f64, 1.4), 0.0);
+/// ```
+pub fn cp_star<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    critical_cp(mach, gamma)
+}
+
+/// Unchecked critical pressure coefficient, safe to evaluate at solver
+/// iterates.
+fn critical_cp<F: Float>(mach: F, gamma: F) -> F {
+    let two = F::from(2.).unwrap();
+    let m2 = mach.powi(2);
+    two / (gamma * m2)
+        * (((two + (gamma - F::one()) * m2) / (gamma + F::one())).powf(gamma / (gamma - F::one()))
+            - F::one())
+}
+
+/// Freestream Mach number for a given critical pressure coefficient
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{cp_star, mach_from_cp_star};
+///
+/// let cp = cp_star(0.7_f64, 1.4);
+/// assert!((mach_from_cp_star(cp, 1.4) - 0.7).abs() < 1e-9);
+/// assert_eq!(mach_from_cp_star(cp_star(0.3_f64, 1.4), 1.4), 0.3);
+/// ```
+pub fn mach_from_cp_star<F: Float>(cp_star: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    // Cp* is even in the Mach number, so Newton may land on the negative
+    // root for low Mach numbers.
+    let f = |mach: F| critical_cp(mach, gamma) - cp_star;
+    FDNewton::new(f)
+        .solve(F::from(0.7).unwrap())
+        .map(F::abs)
+        .unwrap_or(F::nan())
+}
+
+/// Pressure coefficient from the local and freestream Mach numbers
+///
+/// Assumes the flow between freestream and the local point is isentropic.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{cp_from_local_mach, cp_star};
+///
+/// assert_eq!(cp_from_local_mach(0.5_f64, 0.5, 1.4), 0.0);
+/// assert_eq!(cp_from_local_mach(1.0_f64, 0.7, 1.4), cp_star(0.7, 1.4));
+/// ```
+pub fn cp_from_local_mach<F: Float>(m_local: F, m_inf: F, gamma: F) -> F {
+    validate::mach(m_local);
+    validate::mach(m_inf);
+    validate::gamma(gamma);
+    let half = F::from(0.5).unwrap();
+    let stag = |m: F| F::one() + half * (gamma - F::one()) * m.powi(2);
+    ((stag(m_inf) / stag(m_local)).powf(gamma / (gamma - F::one())) - F::one())
+        / (half * gamma * m_inf.powi(2))
+}
+
+/// Local Mach number from the pressure coefficient and freestream Mach number
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{cp_from_local_mach, local_mach_from_cp};
+///
+/// let cp = cp_from_local_mach(1.2_f64, 0.8, 1.4);
+/// assert!((local_mach_from_cp(cp, 0.8, 1.4) - 1.2).abs() < 1e-12);
+/// ```
+pub fn local_mach_from_cp<F: Float>(cp: F, m_inf: F, gamma: F) -> F {
+    validate::mach(m_inf);
+    validate::gamma(gamma);
+    let half = F::from(0.5).unwrap();
+    let p_pinf = F::one() + half * gamma * m_inf.powi(2) * cp;
+    let stag_inf = F::one() + half * (gamma - F::one()) * m_inf.powi(2);
+    let stag = stag_inf * p_pinf.powf((F::one() - gamma) / gamma);
+    ((stag - F::one()) / (half * (gamma - F::one()))).sqrt()
+}
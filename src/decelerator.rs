@@ -0,0 +1,46 @@
+//! Loads on parachutes and other decelerators
+//!
+//! Dynamic pressure is written as q = gamma p M^2 / 2, which equals
+//! rho V^2 / 2 exactly but avoids mixing incompressible pitot reductions into
+//! supersonic deployment conditions.
+use crate::{dynamic_pressure, validate, NormalShock};
+use num::Float;
+
+/// Dynamic pressure behind the normal part of a bow shock
+///
+/// A canopy deploying supersonically sits in the subsonic flow behind its
+/// own bow shock. Below Mach 1 there is no shock and the freestream dynamic
+/// pressure is returned.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{dynamic_pressure, post_shock_dynamic_pressure};
+///
+/// assert_eq!(post_shock_dynamic_pressure(1.4_f64, 500.0, 2.0), 525.0);
+/// assert_eq!(post_shock_dynamic_pressure(1.4_f64, 500.0, 0.5), dynamic_pressure(1.4, 500.0, 0.5));
+/// ```
+pub fn post_shock_dynamic_pressure<F: Float>(gamma: F, p: F, mach: F) -> F {
+    validate::mach(mach);
+    if mach <= F::one() {
+        return dynamic_pressure(gamma, p, mach);
+    }
+    let shock = NormalShock::new(mach, gamma);
+    dynamic_pressure(gamma, p * shock.p2_p1(), shock.mach2())
+}
+
+/// Drag force on a decelerator with drag coefficient `cd` referenced to the
+/// freestream dynamic pressure and area `area`
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::decelerator_drag;
+///
+/// // Mars-like deployment: 16 m canopy at Mach 2 and 500 Pa.
+/// let area = std::f64::consts::PI * 8.0_f64.powi(2);
+/// assert_eq!(decelerator_drag(0.6, area, 1.3, 500.0, 2.0), 156828.30526720246);
+/// ```
+pub fn decelerator_drag<F: Float>(cd: F, area: F, gamma: F, p: F, mach: F) -> F {
+    cd * area * dynamic_pressure(gamma, p, mach)
+}
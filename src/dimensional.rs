@@ -0,0 +1,121 @@
+//! Dimensional conversions between Mach number, velocity and pressure
+use crate::{
+    impact_pressure_ratio, thermally_perfect_p_p0, thermally_perfect_t0, validate, Gas,
+    ThermallyPerfectNormalShock,
+};
+use num::Float;
+
+/// Speed of sound from specific heat ratio, gas constant and temperature
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::speed_of_sound;
+///
+/// assert_eq!(speed_of_sound(1.4_f64, 287.05, 288.15), 340.29228686527705);
+/// ```
+pub fn speed_of_sound<F: Float>(gamma: F, r: F, t: F) -> F {
+    validate::gamma(gamma);
+    (gamma * r * t).sqrt()
+}
+
+/// Velocity from Mach number and speed of sound
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::velocity_from_mach;
+///
+/// assert_eq!(velocity_from_mach(0.8_f64, 340.0), 272.0);
+/// ```
+pub fn velocity_from_mach<F: Float>(mach: F, a: F) -> F {
+    validate::mach(mach);
+    mach * a
+}
+
+/// Dynamic pressure from static pressure and Mach number
+///
+/// Uses q = gamma p M^2 / 2, which equals rho V^2 / 2 for a perfect gas.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::dynamic_pressure;
+///
+/// assert_eq!(dynamic_pressure(1.4_f64, 101325.0, 0.5), 17731.875);
+/// ```
+pub fn dynamic_pressure<F: Float>(gamma: F, p: F, mach: F) -> F {
+    validate::gamma(gamma);
+    validate::mach(mach);
+    gamma * p * mach.powi(2) / F::from(2.).unwrap()
+}
+
+/// Impact pressure, the pitot pressure less the static pressure
+///
+/// Isentropic below Mach 1; above it the pitot probe sees the stagnation
+/// pressure behind a normal shock (Rayleigh pitot formula). The subsonic
+/// branch stays accurate as the Mach number goes to zero, see
+/// [`impact_pressure_ratio`].
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::impact_pressure;
+///
+/// assert_eq!(impact_pressure(0.5_f64, 101325.0, 1.4), 18867.995549848627);
+/// assert_eq!(impact_pressure(2.0_f64, 101325.0, 1.4), 470192.6653593226);
+/// ```
+pub fn impact_pressure<F: Float>(mach: F, p: F, gamma: F) -> F {
+    p * impact_pressure_ratio(mach, gamma)
+}
+
+/// Dimensional state at the stagnation point of a probe or blunt body
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StagnationPoint<F> {
+    /// Stagnation pressure, behind the bow shock in supersonic flow.
+    pub p: F,
+    /// Stagnation temperature.
+    pub t: F,
+    /// Stagnation density.
+    pub rho: F,
+}
+
+/// Stagnation point state of a probe in a freestream of given Mach number,
+/// static pressure and temperature
+///
+/// In supersonic flow the gas passes through the normal part of the bow shock
+/// before being brought to rest isentropically. The thermally perfect
+/// relations are used, so temperature-dependent gas models are handled.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{mach_to_pitot_p02_p1, probe_stagnation_point, CaloricallyPerfect};
+///
+/// let air = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// let stag = probe_stagnation_point(&air, 2.0, 10000.0, 220.0);
+/// assert!((stag.p / 10000.0 - mach_to_pitot_p02_p1(2.0, 1.4)).abs() < 1e-6);
+/// assert!((stag.t - 396.0).abs() < 1e-6);
+/// assert_eq!(stag.rho, 0.4962905018254644);
+/// ```
+pub fn probe_stagnation_point<F: Float, G: Gas<F>>(
+    gas: &G,
+    mach: F,
+    p: F,
+    t: F,
+) -> StagnationPoint<F> {
+    validate::mach(mach);
+    let t0 = thermally_perfect_t0(gas, mach, t);
+    let p0 = if mach <= F::one() {
+        p / thermally_perfect_p_p0(gas, mach, t)
+    } else {
+        let shock = ThermallyPerfectNormalShock::new(gas, mach, t);
+        let (p2, t2) = (p * shock.p2_p1(), t * shock.t2_t1());
+        p2 / thermally_perfect_p_p0(gas, shock.mach2(), t2)
+    };
+    StagnationPoint {
+        p: p0,
+        t: t0,
+        rho: p0 / (gas.r() * t0),
+    }
+}
@@ -0,0 +1,313 @@
+//! Wave tracking in 2-D supersonic ducts
+//!
+//! The duct has a flat lower wall, which may equally be a plane of symmetry,
+//! and an upper wall made of straight segments. Each corner of the upper wall
+//! sends a wave across the duct, which reflects from the lower wall and
+//! returns to the upper wall, where it is either cancelled or reflected again
+//! depending on the local wall angle. Waves are tracked one at a time, and an
+//! expansion fan is represented by a single wave along its mean Mach line.
+use crate::{mach_to_mach_angle, validate, ShockDetached, Wave, WaveFamily, WaveKind};
+use num::Float;
+use std::{error::Error, fmt};
+
+/// Uniform flow region between two waves
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DuctCell<F> {
+    /// Mach number.
+    pub mach: F,
+    /// Flow angle, counterclockwise from the x axis.
+    pub flow_angle: F,
+    /// Static pressure relative to the inlet.
+    pub p_p_inlet: F,
+    /// Stagnation pressure relative to the inlet.
+    pub p0_p0_inlet: F,
+}
+
+/// Straight wave segment in the wave network
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DuctWave<F> {
+    /// Start point `(x, y)`.
+    pub start: (F, F),
+    /// End point `(x, y)`, on the opposite wall or at the duct exit.
+    pub end: (F, F),
+    /// Wave family.
+    pub family: WaveFamily,
+    /// Shock or expansion.
+    pub kind: WaveKind,
+}
+
+/// Cells and waves of a tracked duct flow
+///
+/// `cells[0]` is the inlet flow and `waves[i]` separates `cells[i]` from
+/// `cells[i + 1]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuctFlow<F> {
+    /// Uniform flow regions in streamwise order.
+    pub cells: Vec<DuctCell<F>>,
+    /// Waves between the regions.
+    pub waves: Vec<DuctWave<F>>,
+}
+
+/// Reason a duct flow could not be tracked
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DuctError<F> {
+    /// A wall corner lies where a wave is still crossing the duct, so two
+    /// waves would intersect; wave-wave interactions are not modelled.
+    WaveCrossing {
+        /// Position of the offending corner.
+        x: F,
+    },
+    /// A compression is too strong for an attached oblique shock.
+    Detached(ShockDetached),
+}
+
+impl<F: Float> fmt::Display for DuctError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WaveCrossing { x } => write!(
+                f,
+                "wall corner at x = {} would create crossing waves",
+                x.to_f64().unwrap_or(f64::NAN)
+            ),
+            Self::Detached(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<F: Float + fmt::Debug> Error for DuctError<F> {}
+
+impl<F> From<ShockDetached> for DuctError<F> {
+    fn from(err: ShockDetached) -> Self {
+        Self::Detached(err)
+    }
+}
+
+/// Track the waves in a 2-D duct with a flat lower wall
+///
+/// The upper wall starts at height `height` at x = 0, parallel to the inlet
+/// flow, and turns to the absolute angle `angle` at each `(x, angle)` corner,
+/// given in increasing x. Tracking stops at x = `length`. Returns
+/// [`DuctError::WaveCrossing`] for a corner reached by the wall while an
+/// earlier wave is still crossing the duct.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{track_duct, DuctError, WaveFamily, WaveKind};
+///
+/// // A 5 degree compression corner. Its shock reflects back and forth
+/// // across the duct until it reaches the exit.
+/// let angle = -5.0_f64.to_radians();
+/// let flow = track_duct(2.5, 1.4, 1.0, &[(0.0, angle)], 5.0).unwrap();
+/// assert_eq!(flow.waves[0].kind, WaveKind::Shock);
+/// assert_eq!(flow.waves[1].family, WaveFamily::Left);
+/// assert_eq!(flow.waves.len(), 4);
+///
+/// // Turning the wall back where the reflected shock arrives cancels it.
+/// let x = flow.waves[1].end.0;
+/// let flow = track_duct(2.5, 1.4, 1.0, &[(0.0, angle), (x, 0.0)], 5.0).unwrap();
+/// assert_eq!(flow.waves.len(), 2);
+/// assert_eq!(flow.cells.last().unwrap().flow_angle, 0.0);
+///
+/// // A second corner before the first shock reaches the lower wall.
+/// let corners = [(0.0, angle), (0.5, 2.0 * angle)];
+/// assert_eq!(
+///     track_duct(2.5, 1.4, 1.0, &corners, 1.0),
+///     Err(DuctError::WaveCrossing { x: 0.5 })
+/// );
+/// ```
+pub fn track_duct<F: Float>(
+    mach: F,
+    gamma: F,
+    height: F,
+    corners: &[(F, F)],
+    length: F,
+) -> Result<DuctFlow<F>, DuctError<F>> {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let wall = UpperWall::new(height, corners);
+    let mut cells = vec![DuctCell {
+        mach,
+        flow_angle: F::zero(),
+        p_p_inlet: F::one(),
+        p0_p0_inlet: F::one(),
+    }];
+    let mut waves = Vec::new();
+    // Point on the upper wall where the flow last matched the wall.
+    let mut x_upper = F::zero();
+    let mut next_corner = 0;
+
+    while next_corner < corners.len() && corners[next_corner].0 < length {
+        let (x_corner, angle) = corners[next_corner];
+        next_corner += 1;
+        if x_corner < x_upper {
+            return Err(DuctError::WaveCrossing { x: x_corner });
+        }
+        x_upper = x_corner;
+        let turn = angle - cells.last().unwrap().flow_angle;
+        let Some(mut phi) = add_wave(&mut cells, gamma, turn, WaveFamily::Right)? else {
+            continue;
+        };
+
+        // Follow the wave back and forth across the duct until it leaves or
+        // the upper wall cancels it.
+        loop {
+            let start = (x_upper, wall.y(x_upper));
+            let x_lower = start.0 - start.1 / phi.tan();
+            let end = clip((x_lower, F::zero()), start, length);
+            waves.push(wave(start, end, WaveFamily::Right, &cells));
+            // A wave that makes no downstream progress, e.g. where the walls
+            // meet, ends the tracking.
+            if x_lower.is_nan() || x_lower >= length || x_lower <= start.0 {
+                return finish(cells, waves, &corners[next_corner..], length);
+            }
+
+            let turn = -cells.last().unwrap().flow_angle;
+            let Some(phi_up) = add_wave(&mut cells, gamma, turn, WaveFamily::Left)? else {
+                x_upper = x_lower;
+                break;
+            };
+            let x_hit = wall.intersect(x_lower, phi_up);
+            let end = clip((x_hit, wall.y(x_hit)), (x_lower, F::zero()), length);
+            waves.push(wave((x_lower, F::zero()), end, WaveFamily::Left, &cells));
+            if x_hit >= length || x_hit <= x_lower {
+                return finish(cells, waves, &corners[next_corner..], length);
+            }
+            if let Some(&(x, _)) = corners[next_corner..].iter().find(|&&(x, _)| x < x_hit) {
+                return Err(DuctError::WaveCrossing { x });
+            }
+
+            x_upper = x_hit;
+            let turn = wall.angle(x_hit) - cells.last().unwrap().flow_angle;
+            match add_wave(&mut cells, gamma, turn, WaveFamily::Right)? {
+                Some(next) => phi = next,
+                None => break,
+            }
+        }
+    }
+    Ok(DuctFlow { cells, waves })
+}
+
+/// End the tracking with a wave still in the duct, which no remaining corner
+/// before the exit may cross.
+fn finish<F: Float>(
+    cells: Vec<DuctCell<F>>,
+    waves: Vec<DuctWave<F>>,
+    corners: &[(F, F)],
+    length: F,
+) -> Result<DuctFlow<F>, DuctError<F>> {
+    match corners.iter().find(|&&(x, _)| x < length) {
+        Some(&(x, _)) => Err(DuctError::WaveCrossing { x }),
+        None => Ok(DuctFlow { cells, waves }),
+    }
+}
+
+/// Apply a wave to the last cell, returning the inclination of the wave or
+/// `None` for a negligible turn.
+fn add_wave<F: Float>(
+    cells: &mut Vec<DuctCell<F>>,
+    gamma: F,
+    turn: F,
+    family: WaveFamily,
+) -> Result<Option<F>, ShockDetached> {
+    if turn.abs() <= F::epsilon().sqrt() {
+        return Ok(None);
+    }
+    let cell = *cells.last().unwrap();
+    let w = Wave::new(cell.mach, gamma, cell.flow_angle, turn, family)?;
+    let phi = match w.kind() {
+        WaveKind::Shock => w.inclination(),
+        WaveKind::Expansion => {
+            let trailing = family.inclination(w.flow_angle(), mach_to_mach_angle(w.mach2()));
+            (w.inclination() + trailing) / F::from(2.).unwrap()
+        }
+    };
+    cells.push(DuctCell {
+        mach: w.mach2(),
+        flow_angle: w.flow_angle(),
+        p_p_inlet: cell.p_p_inlet * w.p2_p1(),
+        p0_p0_inlet: cell.p0_p0_inlet * w.p02_p01(),
+    });
+    Ok(Some(phi))
+}
+
+fn wave<F: Float>(
+    start: (F, F),
+    end: (F, F),
+    family: WaveFamily,
+    cells: &[DuctCell<F>],
+) -> DuctWave<F> {
+    let n = cells.len();
+    let kind = if cells[n - 1].p_p_inlet > cells[n - 2].p_p_inlet {
+        WaveKind::Shock
+    } else {
+        WaveKind::Expansion
+    };
+    DuctWave {
+        start,
+        end,
+        family,
+        kind,
+    }
+}
+
+/// Shorten a wave ending beyond the duct exit so that it ends at the exit.
+fn clip<F: Float>(end: (F, F), start: (F, F), length: F) -> (F, F) {
+    if end.0 <= length {
+        return end;
+    }
+    let s = (length - start.0) / (end.0 - start.0);
+    (length, start.1 + s * (end.1 - start.1))
+}
+
+/// Piecewise-straight upper wall.
+struct UpperWall<F> {
+    /// Start x, start y and angle of each segment.
+    segments: Vec<(F, F, F)>,
+}
+
+impl<F: Float> UpperWall<F> {
+    fn new(height: F, corners: &[(F, F)]) -> Self {
+        let mut segments = vec![(F::zero(), height, F::zero())];
+        for &(x, angle) in corners {
+            let &(xs, ys, a) = segments.last().unwrap();
+            segments.push((x, ys + (x - xs) * a.tan(), angle));
+        }
+        Self { segments }
+    }
+
+    fn segment(&self, x: F) -> (F, F, F) {
+        *self
+            .segments
+            .iter()
+            .rev()
+            .find(|s| s.0 <= x)
+            .unwrap_or(&self.segments[0])
+    }
+
+    fn y(&self, x: F) -> F {
+        let (xs, ys, a) = self.segment(x);
+        ys + (x - xs) * a.tan()
+    }
+
+    fn angle(&self, x: F) -> F {
+        self.segment(x).2
+    }
+
+    /// Where a wave leaving the lower wall at `x0` with inclination `phi`
+    /// meets the upper wall.
+    fn intersect(&self, x0: F, phi: F) -> F {
+        let ends = self.segments.iter().skip(1).map(|s| s.0);
+        for (&(xs, ys, a), xe) in self
+            .segments
+            .iter()
+            .zip(ends.map(Some).chain(std::iter::once(None)))
+        {
+            let x = (ys - a.tan() * xs + phi.tan() * x0) / (phi.tan() - a.tan());
+            if x >= xs.max(x0) && xe.is_none_or(|xe| x <= xe) {
+                return x;
+            }
+        }
+        F::infinity()
+    }
+}
@@ -0,0 +1,134 @@
+//! Evaluation of quantities over a Mach-altitude flight envelope
+//!
+//! The freestream at each grid point follows the standard atmosphere, so any
+//! quantity that depends on the flight condition can be tabulated for
+//! contour plots of the envelope.
+use crate::{mach_from_probe_tr_t, standard_atmosphere, AtmosphereState, RecoveryFactor};
+use num::Float;
+
+/// Freestream flight condition at one envelope grid point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlightCondition<F> {
+    /// Flight Mach number.
+    pub mach: F,
+    /// Geopotential altitude.
+    pub altitude: F,
+    /// Standard atmosphere at the altitude.
+    pub atmosphere: AtmosphereState<F>,
+}
+
+impl<F: Float> FlightCondition<F> {
+    /// Flight velocity.
+    pub fn velocity(&self) -> F {
+        self.mach * self.atmosphere.a
+    }
+
+    /// Freestream dynamic pressure.
+    pub fn dynamic_pressure(&self) -> F {
+        F::from(0.5).unwrap() * self.atmosphere.rho * self.velocity().powi(2)
+    }
+}
+
+/// Quantity tabulated over a Mach-altitude grid
+///
+/// `values[i][j]` is the value at `altitudes[i]` and `machs[j]`, so each row
+/// is one altitude.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvelopeTable<F, T> {
+    /// Mach numbers of the grid columns.
+    pub machs: Vec<F>,
+    /// Altitudes of the grid rows.
+    pub altitudes: Vec<F>,
+    /// Tabulated values, one row per altitude.
+    pub values: Vec<Vec<T>>,
+}
+
+/// Evaluate a quantity at every point of a Mach-altitude grid
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{envelope_sweep, mach_to_t_t0, Transport};
+///
+/// let machs = [0.5_f64, 1.0, 2.0];
+/// let altitudes = [0.0, 10000.0];
+///
+/// // Unit Reynolds number and stagnation temperature across the envelope.
+/// let air = Transport::air();
+/// let reynolds = envelope_sweep(&machs, &altitudes, |c| {
+///     air.reynolds(c.atmosphere.rho, c.velocity(), 1.0, c.atmosphere.t)
+/// });
+/// let t0 = envelope_sweep(&machs, &altitudes, |c| c.atmosphere.t / mach_to_t_t0(c.mach, 1.4));
+/// assert_eq!(reynolds.values[0][0], 11648708.8406659);
+/// assert_eq!(t0.values[1][2], 401.66999999999996);
+/// ```
+pub fn envelope_sweep<F: Float, T>(
+    machs: &[F],
+    altitudes: &[F],
+    f: impl Fn(&FlightCondition<F>) -> T,
+) -> EnvelopeTable<F, T> {
+    let values = altitudes
+        .iter()
+        .map(|&altitude| {
+            let atmosphere = standard_atmosphere(altitude);
+            machs
+                .iter()
+                .map(|&mach| {
+                    f(&FlightCondition {
+                        mach,
+                        altitude,
+                        atmosphere,
+                    })
+                })
+                .collect()
+        })
+        .collect();
+    EnvelopeTable {
+        machs: machs.to_vec(),
+        altitudes: altitudes.to_vec(),
+        values,
+    }
+}
+
+/// Mach number at which the recovery temperature reaches a material limit,
+/// for each altitude
+///
+/// Returns `(altitude, mach)` pairs tracing the thermal placard: flight above
+/// the returned Mach number exceeds `t_limit`. Air is a perfect gas with
+/// gamma = 1.4; use [`RecoveryFactor::Fixed`] with a value of one for the
+/// stagnation temperature. Where the ambient temperature already exceeds the
+/// limit the Mach number is zero.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{adiabatic_wall_temperature, standard_atmosphere, thermal_placard, RecoveryFactor};
+///
+/// let altitudes = [0.0_f64, 11000.0, 20000.0];
+/// let placard = thermal_placard(&altitudes, 400.0, RecoveryFactor::Turbulent, 0.71);
+/// assert_eq!(placard[0].1, 1.4749726236785061);
+/// assert_eq!(placard[1].1, 2.17789224182467);
+///
+/// let t = standard_atmosphere(11000.0).t;
+/// let t_aw = adiabatic_wall_temperature(t, placard[1].1, 1.4, RecoveryFactor::Turbulent, 0.71);
+/// assert!((t_aw - 400.0).abs() < 1e-9);
+/// ```
+pub fn thermal_placard<F: Float>(
+    altitudes: &[F],
+    t_limit: F,
+    recovery: RecoveryFactor<F>,
+    prandtl: F,
+) -> Vec<(F, F)> {
+    let gamma = F::from(1.4).unwrap();
+    altitudes
+        .iter()
+        .map(|&altitude| {
+            let t = standard_atmosphere(altitude).t;
+            let tr_t = (t_limit / t).max(F::one());
+            (
+                altitude,
+                mach_from_probe_tr_t(tr_t, gamma, recovery, prandtl),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,130 @@
+//! Centred Prandtl-Meyer expansion fans
+//!
+//! A supersonic stream turning away from itself by an angle theta expands
+//! isentropically through a centred fan. The downstream Mach number follows
+//! from nu(M2) = nu(M1) + theta and the static ratios from the isentropic
+//! relations, since the stagnation conditions are unchanged across the fan.
+use crate::{
+    mach_from_p_p0, mach_from_pm_angle, mach_to_mach_angle, mach_to_p_p0, mach_to_pm_angle,
+    mach_to_rho_rho0, mach_to_t_t0, validate,
+};
+use num::Float;
+
+/// Downstream Mach number after turning through a centred expansion fan
+///
+/// The turn angle `theta` is in radians.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::expansion_mach2;
+///
+/// assert_eq!(expansion_mach2(2.0_f64, 1.4, 0.0), 2.0);
+/// assert_eq!(expansion_mach2(2.0_f64, 1.4, 10_f64.to_radians()), 2.38488715459307);
+/// ```
+pub fn expansion_mach2<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    mach_from_pm_angle(mach_to_pm_angle(mach, gamma) + theta, gamma)
+}
+
+/// Static pressure ratio p2/p1 across a centred expansion fan
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::expansion_p2_p1;
+///
+/// assert_eq!(expansion_p2_p1(2.0_f64, 1.4, 10_f64.to_radians()), 0.547968731276905);
+/// ```
+pub fn expansion_p2_p1<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    let mach2 = expansion_mach2(mach, gamma, theta);
+    mach_to_p_p0(mach2, gamma) / mach_to_p_p0(mach, gamma)
+}
+
+/// Static temperature ratio T2/T1 across a centred expansion fan
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::expansion_t2_t1;
+///
+/// assert_eq!(expansion_t2_t1(2.0_f64, 1.4, 10_f64.to_radians()), 0.8420905495102075);
+/// ```
+pub fn expansion_t2_t1<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    let mach2 = expansion_mach2(mach, gamma, theta);
+    mach_to_t_t0(mach2, gamma) / mach_to_t_t0(mach, gamma)
+}
+
+/// Density ratio rho2/rho1 across a centred expansion fan
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::expansion_rho2_rho1;
+///
+/// assert_eq!(expansion_rho2_rho1(2.0_f64, 1.4, 10_f64.to_radians()), 0.6507242381423528);
+/// ```
+pub fn expansion_rho2_rho1<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    let mach2 = expansion_mach2(mach, gamma, theta);
+    mach_to_rho_rho0(mach2, gamma) / mach_to_rho_rho0(mach, gamma)
+}
+
+/// Turn angle in radians needed to expand to a static pressure ratio p2/p1
+///
+/// The pressure ratio must lie in (0, 1]; a ratio of one needs no turn.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{expansion_p2_p1, expansion_theta_from_p2_p1};
+///
+/// let p2_p1 = expansion_p2_p1(2.0_f64, 1.4, 10_f64.to_radians());
+/// assert_eq!(expansion_theta_from_p2_p1(2.0, 1.4, p2_p1).to_degrees(), 10.000000000000002);
+/// ```
+pub fn expansion_theta_from_p2_p1<F: Float>(mach: F, gamma: F, p2_p1: F) -> F {
+    validate::ratio("p2_p1", p2_p1);
+    let mach2 = mach_from_p_p0(p2_p1 * mach_to_p_p0(mach, gamma), gamma);
+    mach_to_pm_angle(mach2, gamma) - mach_to_pm_angle(mach, gamma)
+}
+
+/// Local Mach number and flow deflection along a ray inside a centred fan
+///
+/// The upstream flow runs along the x axis and turns through `theta` away
+/// from the fan. The ray angle `phi` is measured from the upstream flow
+/// direction towards the fan, so the lead Mach wave lies at `phi = mu1` and
+/// the tail wave at `phi = mu2 - theta`. Returns `(mach, deflection)` where the
+/// deflection is the local turn from the upstream direction, between zero and
+/// `theta`. Rays ahead of the lead wave see the upstream state and rays behind
+/// the tail wave the downstream state. All angles are in radians.
+///
+/// Along each ray mu(M) - nu(M) is constant, which inverts in closed form
+/// because mu - nu = pi/2 - atan(k sqrt(M^2 - 1)) / k with
+/// k = sqrt((gamma - 1) / (gamma + 1)).
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{expansion_fan_ray, expansion_mach2, mach_to_mach_angle};
+///
+/// let theta = 10_f64.to_radians();
+/// let lead = mach_to_mach_angle(2.0_f64);
+/// let tail = mach_to_mach_angle(expansion_mach2(2.0, 1.4, theta)) - theta;
+/// assert_eq!(expansion_fan_ray(2.0, 1.4, theta, lead), (2.0, 0.0));
+/// assert_eq!(expansion_fan_ray(2.0, 1.4, theta, tail), (2.38488715459307, 0.17453292519943295));
+/// assert_eq!(expansion_fan_ray(2.0, 1.4, theta, 0.5 * (lead + tail)), (2.1819281420130667, 0.08521919979398151));
+/// ```
+pub fn expansion_fan_ray<F: Float>(mach: F, gamma: F, theta: F, phi: F) -> (F, F) {
+    let nu1 = mach_to_pm_angle(mach, gamma);
+    let lead = mach_to_mach_angle(mach);
+    if phi >= lead {
+        return (mach, F::zero());
+    }
+    let mach2 = expansion_mach2(mach, gamma, theta);
+    if phi <= mach_to_mach_angle(mach2) - theta {
+        return (mach2, theta);
+    }
+    let half_pi = F::from(std::f64::consts::FRAC_PI_2).unwrap();
+    let k = ((gamma - F::one()) / (gamma + F::one())).sqrt();
+    let beta = (k * (half_pi + nu1 - phi)).tan() / k;
+    let local = (F::one() + beta.powi(2)).sqrt();
+    (local, mach_to_pm_angle(local, gamma) - nu1)
+}
@@ -0,0 +1,266 @@
+//! Post-processing of 1-D field data such as CFD solutions or traverses
+use crate::validate;
+use num::Float;
+use std::ops::Range;
+
+/// Pre- and post-shock states of a shock found in field data
+///
+/// Returned by [`locate_shocks`]. The upstream state is the low-pressure side
+/// of the shock and the downstream state the high-pressure side, whichever way
+/// the arrays are ordered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShockJump<F> {
+    /// Index of the sample taken as the upstream state.
+    pub upstream: usize,
+    /// Index of the sample taken as the downstream state.
+    pub downstream: usize,
+    /// Upstream density.
+    pub rho1: F,
+    /// Upstream velocity.
+    pub u1: F,
+    /// Upstream static pressure.
+    pub p1: F,
+    /// Downstream density.
+    pub rho2: F,
+    /// Downstream velocity.
+    pub u2: F,
+    /// Downstream static pressure.
+    pub p2: F,
+    /// Shock velocity in the frame of the field data, from mass conservation.
+    pub shock_speed: F,
+    /// Upstream Mach number relative to the shock that best fits the measured
+    /// pressure and density ratios.
+    pub mach: F,
+    /// Root mean square of the logarithmic pressure and density ratio
+    /// residuals at `mach`; large values indicate a poor Rankine-Hugoniot fit.
+    pub residual: F,
+}
+
+/// Locate shocks in 1-D arrays of density, velocity and static pressure
+///
+/// A shock is a run of consecutive samples over which the pressure rises
+/// monotonically and each step exceeds `threshold` relative to the lower
+/// pressure, so that shocks smeared over several cells are reported once.
+/// The ends of the run are taken as the pre- and post-shock states, and the
+/// equivalent shock Mach number is fitted to the measured pressure and density
+/// ratios with the Rankine-Hugoniot relations.
+///
+/// Steep but isentropic compressions also pass the threshold test; a large
+/// `residual` in the result flags them.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{locate_shocks, normal_p2_p1, normal_rho2_rho1};
+///
+/// // Stationary Mach 2 shock smeared over two cells.
+/// let gamma = 1.4_f64;
+/// let u1 = 2.0 * gamma.sqrt();
+/// let rho2 = normal_rho2_rho1(2.0, gamma);
+/// let p2 = normal_p2_p1(2.0, gamma);
+///
+/// let rho = [1.0, 1.0, 1.0, 0.5 * (1.0 + rho2), rho2, rho2];
+/// let u = [u1, u1, u1, u1 / (0.5 * (1.0 + rho2)), u1 / rho2, u1 / rho2];
+/// let p = [1.0, 1.0, 1.0, 0.5 * (1.0 + p2), p2, p2];
+///
+/// let shocks = locate_shocks(&rho, &u, &p, gamma, 0.05);
+/// assert_eq!(shocks.len(), 1);
+/// assert_eq!((shocks[0].upstream, shocks[0].downstream), (2, 4));
+/// assert!((shocks[0].mach - 2.0).abs() < 1e-6);
+/// assert!(shocks[0].shock_speed.abs() < 1e-12);
+/// ```
+pub fn locate_shocks<F: Float>(
+    rho: &[F],
+    u: &[F],
+    p: &[F],
+    gamma: F,
+    threshold: F,
+) -> Vec<ShockJump<F>> {
+    validate::gamma(gamma);
+    let n = rho.len().min(u.len()).min(p.len());
+    // Sign of each pressure step that counts as steep: +1 rising, -1 falling.
+    let steep = |i: usize| -> i32 {
+        let dp = p[i + 1] - p[i];
+        if dp.abs() > threshold * p[i].min(p[i + 1]) {
+            if dp > F::zero() {
+                1
+            } else {
+                -1
+            }
+        } else {
+            0
+        }
+    };
+
+    let mut shocks = Vec::new();
+    let mut i = 0;
+    while i + 1 < n {
+        let sign = steep(i);
+        if sign == 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i + 1 < n && steep(i) == sign {
+            i += 1;
+        }
+        let (upstream, downstream) = if sign > 0 { (start, i) } else { (i, start) };
+        shocks.push(shock_jump(rho, u, p, gamma, upstream, downstream));
+    }
+    shocks
+}
+
+fn shock_jump<F: Float>(
+    rho: &[F],
+    u: &[F],
+    p: &[F],
+    gamma: F,
+    upstream: usize,
+    downstream: usize,
+) -> ShockJump<F> {
+    let (rho1, u1, p1) = (rho[upstream], u[upstream], p[upstream]);
+    let (rho2, u2, p2) = (rho[downstream], u[downstream], p[downstream]);
+    let (mach, residual) = fit_shock_mach(p2 / p1, rho2 / rho1, gamma);
+    ShockJump {
+        upstream,
+        downstream,
+        rho1,
+        u1,
+        p1,
+        rho2,
+        u2,
+        p2,
+        shock_speed: (rho2 * u2 - rho1 * u1) / (rho2 - rho1),
+        mach,
+        residual,
+    }
+}
+
+/// Least-squares fit of the shock Mach number to pressure and density ratios.
+fn fit_shock_mach<F: Float>(p2_p1: F, rho2_rho1: F, gamma: F) -> (F, F) {
+    let two = F::from(2.0).unwrap();
+    let objective = |mach: F| {
+        let m2 = mach.powi(2);
+        let p = two * gamma / (gamma + F::one()) * (m2 - F::one()) + F::one();
+        let r = (gamma + F::one()) * m2 / ((gamma - F::one()) * m2 + two);
+        (p2_p1.ln() - p.ln()).powi(2) + (rho2_rho1.ln() - r.ln()).powi(2)
+    };
+
+    // The pressure ratio alone fixes the Mach number, so bracket around it.
+    let mach_p = (F::one() + (gamma + F::one()) / (two * gamma) * (p2_p1 - F::one()))
+        .max(F::one())
+        .sqrt();
+    let mut lo = F::one();
+    let mut hi = two * mach_p + F::one();
+    let ratio = F::from(0.5 * (5.0_f64.sqrt() - 1.0)).unwrap();
+    let tol = F::epsilon().sqrt();
+    let mut a = hi - ratio * (hi - lo);
+    let mut b = lo + ratio * (hi - lo);
+    while hi - lo > tol * hi {
+        if objective(a) < objective(b) {
+            hi = b;
+            b = a;
+            a = hi - ratio * (hi - lo);
+        } else {
+            lo = a;
+            a = b;
+            b = lo + ratio * (hi - lo);
+        }
+    }
+    let mach = (lo + hi) / two;
+    (mach, (objective(mach) / two).sqrt())
+}
+
+/// Entropy deviation (s - s_ref)/R from pressure and temperature samples
+///
+/// Pressures and temperatures may be static or stagnation values, as long as
+/// the reference state is of the same kind. Zero everywhere for a flow that is
+/// isentropic with respect to the reference state.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{isentropic_deviation_p_t, mach_to_p_p0, mach_to_t_t0};
+///
+/// let p = [1.0_f64, mach_to_p_p0(2.0, 1.4), 0.9 * mach_to_p_p0(2.0, 1.4)];
+/// let t = [1.0, mach_to_t_t0(2.0, 1.4), mach_to_t_t0(2.0, 1.4)];
+/// let ds_r = isentropic_deviation_p_t(&p, &t, 1.0, 1.0, 1.4);
+///
+/// assert_eq!(ds_r[0], 0.0);
+/// assert!(ds_r[1].abs() < 1e-12);
+/// assert_eq!(ds_r[2], 0.10536051565782634);
+/// ```
+pub fn isentropic_deviation_p_t<F: Float>(
+    p: &[F],
+    t: &[F],
+    p_ref: F,
+    t_ref: F,
+    gamma: F,
+) -> Vec<F> {
+    validate::gamma(gamma);
+    p.iter()
+        .zip(t)
+        .map(|(&p, &t)| gamma / (gamma - F::one()) * (t / t_ref).ln() - (p / p_ref).ln())
+        .collect()
+}
+
+/// Entropy deviation (s - s_ref)/R from pressure and density samples
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{isentropic_deviation_p_rho, mach_to_p_p0, mach_to_rho_rho0};
+///
+/// let p = [1.0_f64, mach_to_p_p0(2.0, 1.4)];
+/// let rho = [1.0, mach_to_rho_rho0(2.0, 1.4)];
+/// let ds_r = isentropic_deviation_p_rho(&p, &rho, 1.0, 1.0, 1.4);
+///
+/// assert_eq!(ds_r[0], 0.0);
+/// assert!(ds_r[1].abs() < 1e-12);
+/// ```
+pub fn isentropic_deviation_p_rho<F: Float>(
+    p: &[F],
+    rho: &[F],
+    p_ref: F,
+    rho_ref: F,
+    gamma: F,
+) -> Vec<F> {
+    validate::gamma(gamma);
+    p.iter()
+        .zip(rho)
+        .map(|(&p, &rho)| ((p / p_ref).ln() - gamma * (rho / rho_ref).ln()) / (gamma - F::one()))
+        .collect()
+}
+
+/// Index ranges where the entropy deviation exceeds a tolerance
+///
+/// Takes the output of [`isentropic_deviation_p_t`] or
+/// [`isentropic_deviation_p_rho`] and groups consecutive samples with
+/// `|ds_r| > tolerance` into half-open ranges.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::non_isentropic_regions;
+///
+/// let ds_r = [0.0, 0.001, 0.05, 0.08, 0.0, -0.02, 0.0];
+/// assert_eq!(non_isentropic_regions(&ds_r, 0.01), vec![2..4, 5..6]);
+/// ```
+pub fn non_isentropic_regions<F: Float>(ds_r: &[F], tolerance: F) -> Vec<Range<usize>> {
+    let mut regions = Vec::new();
+    let mut start = None;
+    for (i, ds) in ds_r.iter().enumerate() {
+        match (ds.abs() > tolerance, start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                regions.push(s..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        regions.push(s..ds_r.len());
+    }
+    regions
+}
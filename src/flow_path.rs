@@ -0,0 +1,349 @@
+//! Chaining of flow processes along a path
+//!
+//! [`FlowState`] carries a Mach number and the stagnation pressure relative to
+//! the start of the path through a sequence of shocks, expansions and area
+//! changes, so that losses accumulate without bookkeeping by hand. Stagnation
+//! temperature is constant along the path since every process is adiabatic.
+//!
+//! [`FlowState::traced`] starts a [`TracedFlowState`], which applies the same
+//! processes but also records each one with its losses, so that a long chain
+//! can be reviewed step by step or exported as CSV.
+use crate::{
+    mach_from_a_ac, mach_from_pm_angle, mach_to_p_p0, mach_to_pm_angle, mach_to_t_t0, NormalShock,
+    ObliqueShock, Quantity, ShockDetached,
+};
+use num::Float;
+use std::fmt::{self, Write};
+
+/// Flow state at a point along a chain of processes
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{FlowState, ShockDetached};
+///
+/// // Two-shock external compression followed by subsonic diffusion.
+/// let exit = FlowState::new(3.0_f64, 1.4)
+///     .turn_oblique(0.2)?
+///     .through_normal_shock()
+///     .to_area_ratio(2.0);
+///
+/// assert_eq!(exit.mach(), 0.3059038341891082);
+/// assert_eq!(exit.p0_p0_inlet(), 0.49849024394241975);
+/// # Ok::<(), ShockDetached>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowState<F> {
+    mach: F,
+    gamma: F,
+    p0_p0_inlet: F,
+}
+
+impl<F: Float> FlowState<F> {
+    /// Start of a path at a given Mach number.
+    pub fn new(mach: F, gamma: F) -> Self {
+        Self {
+            mach,
+            gamma,
+            p0_p0_inlet: F::one(),
+        }
+    }
+
+    /// Mach number.
+    pub fn mach(&self) -> F {
+        self.mach
+    }
+
+    /// Specific heat ratio.
+    pub fn gamma(&self) -> F {
+        self.gamma
+    }
+
+    /// Stagnation pressure relative to the start of the path.
+    pub fn p0_p0_inlet(&self) -> F {
+        self.p0_p0_inlet
+    }
+
+    /// Static pressure relative to the stagnation pressure at the start of
+    /// the path.
+    pub fn p_p0_inlet(&self) -> F {
+        self.p0_p0_inlet * mach_to_p_p0(self.mach, self.gamma)
+    }
+
+    /// Static to stagnation temperature ratio, which is also relative to the
+    /// start of the path since stagnation temperature is conserved.
+    pub fn t_t0(&self) -> F {
+        mach_to_t_t0(self.mach, self.gamma)
+    }
+
+    /// Every property of the state, labelled with its registered quantity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::FlowState;
+    ///
+    /// let state = FlowState::new(2.0_f64, 1.4).through_normal_shock();
+    /// let (quantity, value) = state.quantities()[2];
+    /// assert_eq!(quantity.symbol, "p0/p0_inlet");
+    /// assert_eq!(value, 0.7208738614847454);
+    /// ```
+    pub fn quantities(&self) -> Vec<(&'static Quantity, F)> {
+        vec![
+            (&Quantity::MACH, self.mach),
+            (&Quantity::GAMMA, self.gamma),
+            (&Quantity::P0_P0_INLET, self.p0_p0_inlet),
+            (&Quantity::P_P0_INLET, self.p_p0_inlet()),
+            (&Quantity::T_T0, self.t_t0()),
+        ]
+    }
+
+    /// Start recording the processes applied from this state on.
+    pub fn traced(self) -> TracedFlowState<F> {
+        TracedFlowState {
+            state: self,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Pass through a normal shock; the flow must be supersonic.
+    pub fn through_normal_shock(self) -> Self {
+        let shock = NormalShock::new(self.mach, self.gamma);
+        Self {
+            mach: shock.mach2(),
+            p0_p0_inlet: self.p0_p0_inlet * shock.p02_p01(),
+            ..self
+        }
+    }
+
+    /// Turn the flow through a compressive deflection `theta` in radians by a
+    /// weak oblique shock.
+    pub fn turn_oblique(self, theta: F) -> Result<Self, ShockDetached> {
+        let shock = ObliqueShock::new(self.mach, self.gamma, theta)?;
+        Ok(Self {
+            mach: shock.mach2(),
+            p0_p0_inlet: self.p0_p0_inlet * shock.p02_p01(),
+            ..self
+        })
+    }
+
+    /// Expand the flow isentropically by turning it through `delta_nu`
+    /// radians of Prandtl-Meyer angle; the flow must be supersonic.
+    pub fn expand_pm(self, delta_nu: F) -> Self {
+        let nu = mach_to_pm_angle(self.mach, self.gamma) + delta_nu;
+        Self {
+            mach: mach_from_pm_angle(nu, self.gamma),
+            ..self
+        }
+    }
+
+    /// Change area isentropically to a critical area ratio `a_ac`, staying on
+    /// the current side of Mach 1. The ratio is relative to the sonic area at
+    /// the current stagnation pressure, which grows across every shock.
+    pub fn to_area_ratio(self, a_ac: F) -> Self {
+        Self {
+            mach: mach_from_a_ac(a_ac, self.gamma, self.mach > F::one()),
+            ..self
+        }
+    }
+}
+
+/// Process applied to a [`FlowState`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FlowProcess<F> {
+    /// Normal shock.
+    NormalShock,
+    /// Weak oblique shock with a deflection in radians.
+    ObliqueShock {
+        /// Flow deflection in radians.
+        theta: F,
+    },
+    /// Prandtl-Meyer expansion through a turn in radians.
+    Expansion {
+        /// Increase in Prandtl-Meyer angle in radians.
+        delta_nu: F,
+    },
+    /// Isentropic area change to a critical area ratio.
+    AreaChange {
+        /// Critical area ratio reached.
+        a_ac: F,
+    },
+}
+
+impl<F> FlowProcess<F> {
+    /// Short name of the process.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NormalShock => "normal_shock",
+            Self::ObliqueShock { .. } => "oblique_shock",
+            Self::Expansion { .. } => "expansion",
+            Self::AreaChange { .. } => "area_change",
+        }
+    }
+}
+
+impl<F: Float> FlowProcess<F> {
+    /// Parameter of the process, if it has one.
+    pub fn parameter(&self) -> Option<F> {
+        match *self {
+            Self::NormalShock => None,
+            Self::ObliqueShock { theta } => Some(theta),
+            Self::Expansion { delta_nu } => Some(delta_nu),
+            Self::AreaChange { a_ac } => Some(a_ac),
+        }
+    }
+}
+
+/// One recorded step of a [`TracedFlowState`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowStep<F> {
+    /// Process applied.
+    pub process: FlowProcess<F>,
+    /// Mach number before the process.
+    pub mach_in: F,
+    /// Mach number after the process.
+    pub mach_out: F,
+    /// Stagnation pressure ratio across the process.
+    pub p02_p01: F,
+    /// Entropy generated by the process, (s2 - s1)/R.
+    pub ds_r: F,
+    /// Stagnation pressure after the process relative to the start of the
+    /// path.
+    pub p0_p0_inlet: F,
+}
+
+/// Flow state along a chain of processes with a record of every step
+///
+/// The methods mirror those of [`FlowState`]. `Display` prints the record as
+/// a table and [`TracedFlowState::to_csv`] exports it.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{FlowProcess, FlowState, ShockDetached};
+///
+/// let path = FlowState::new(3.0_f64, 1.4)
+///     .traced()
+///     .turn_oblique(0.2)?
+///     .through_normal_shock()
+///     .to_area_ratio(2.0);
+///
+/// assert_eq!(path.state().p0_p0_inlet(), 0.49849024394241975);
+/// assert_eq!(path.steps().len(), 3);
+/// assert_eq!(path.steps()[1].process, FlowProcess::NormalShock);
+/// assert_eq!(path.steps()[1].ds_r, 0.6417146372793674);
+/// assert_eq!(path.steps()[2].p02_p01, 1.0);
+///
+/// let csv = path.to_csv();
+/// assert!(csv.starts_with("process,parameter,mach_in,mach_out,p02_p01,ds_r,p0_p0_inlet\n"));
+/// assert_eq!(csv.lines().count(), 4);
+/// # Ok::<(), ShockDetached>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TracedFlowState<F> {
+    state: FlowState<F>,
+    steps: Vec<FlowStep<F>>,
+}
+
+impl<F: Float> TracedFlowState<F> {
+    /// Current state.
+    pub fn state(&self) -> FlowState<F> {
+        self.state
+    }
+
+    /// Steps applied so far, in order.
+    pub fn steps(&self) -> &[FlowStep<F>] {
+        &self.steps
+    }
+
+    /// Pass through a normal shock; the flow must be supersonic.
+    pub fn through_normal_shock(self) -> Self {
+        let next = self.state.through_normal_shock();
+        self.record(FlowProcess::NormalShock, next)
+    }
+
+    /// Turn the flow through a compressive deflection `theta` in radians by a
+    /// weak oblique shock.
+    pub fn turn_oblique(self, theta: F) -> Result<Self, ShockDetached> {
+        let next = self.state.turn_oblique(theta)?;
+        Ok(self.record(FlowProcess::ObliqueShock { theta }, next))
+    }
+
+    /// Expand the flow isentropically by turning it through `delta_nu`
+    /// radians of Prandtl-Meyer angle; the flow must be supersonic.
+    pub fn expand_pm(self, delta_nu: F) -> Self {
+        let next = self.state.expand_pm(delta_nu);
+        self.record(FlowProcess::Expansion { delta_nu }, next)
+    }
+
+    /// Change area isentropically to a critical area ratio `a_ac`, staying on
+    /// the current side of Mach 1.
+    pub fn to_area_ratio(self, a_ac: F) -> Self {
+        let next = self.state.to_area_ratio(a_ac);
+        self.record(FlowProcess::AreaChange { a_ac }, next)
+    }
+
+    /// Record of the steps as CSV with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("process,parameter,mach_in,mach_out,p02_p01,ds_r,p0_p0_inlet\n");
+        for step in &self.steps {
+            let parameter = step.process.parameter().map(show);
+            writeln!(
+                csv,
+                "{},{},{},{},{},{},{}",
+                step.process.name(),
+                parameter.map(|p| p.to_string()).unwrap_or_default(),
+                show(step.mach_in),
+                show(step.mach_out),
+                show(step.p02_p01),
+                show(step.ds_r),
+                show(step.p0_p0_inlet)
+            )
+            .unwrap();
+        }
+        csv
+    }
+
+    fn record(mut self, process: FlowProcess<F>, next: FlowState<F>) -> Self {
+        let p02_p01 = next.p0_p0_inlet / self.state.p0_p0_inlet;
+        self.steps.push(FlowStep {
+            process,
+            mach_in: self.state.mach,
+            mach_out: next.mach,
+            p02_p01,
+            ds_r: p02_p01.recip().ln(),
+            p0_p0_inlet: next.p0_p0_inlet,
+        });
+        self.state = next;
+        self
+    }
+}
+
+impl<F: Float> fmt::Display for TracedFlowState<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<14} {:>10} {:>10} {:>10} {:>10} {:>10} {:>12}",
+            "process", "parameter", "mach_in", "mach_out", "p02_p01", "ds_r", "p0_p0_inlet"
+        )?;
+        for step in &self.steps {
+            let parameter = step.process.parameter().map(show);
+            writeln!(
+                f,
+                "{:<14} {:>10} {:>10.4} {:>10.4} {:>10.4} {:>10.4} {:>12.4}",
+                step.process.name(),
+                parameter.map(|p| format!("{:.4}", p)).unwrap_or_default(),
+                show(step.mach_in),
+                show(step.mach_out),
+                show(step.p02_p01),
+                show(step.ds_r),
+                show(step.p0_p0_inlet)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn show<F: Float>(value: F) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
@@ -0,0 +1,200 @@
+//! Gas models for dimensional calculations
+//!
+//! The ratio functions elsewhere in the crate take a constant `gamma`
+//! directly. Dimensional and higher-level APIs instead take a [`Gas`], so they
+//! can work with models whose properties vary with temperature. The default
+//! model is [`CaloricallyPerfect`].
+use crate::validate;
+use num::Float;
+
+/// Thermodynamic model of a thermally perfect gas, p = rho R T
+///
+/// Specific heats may vary with temperature. Implementors provide the gas
+/// constant, cp, enthalpy and the temperature-dependent part of the entropy;
+/// the other properties follow from these.
+pub trait Gas<F: Float> {
+    /// Specific gas constant.
+    fn r(&self) -> F;
+
+    /// Specific heat at constant pressure.
+    fn cp(&self, t: F) -> F;
+
+    /// Specific enthalpy, relative to a datum chosen by the model.
+    fn h(&self, t: F) -> F;
+
+    /// Temperature-dependent part of the specific entropy, the integral of
+    /// cp / T, so that s(T, p) = s0(T) - R ln(p) up to a constant.
+    fn s0(&self, t: F) -> F;
+
+    /// Specific heat at constant volume.
+    fn cv(&self, t: F) -> F {
+        self.cp(t) - self.r()
+    }
+
+    /// Specific heat ratio.
+    fn gamma(&self, t: F) -> F {
+        self.cp(t) / self.cv(t)
+    }
+
+    /// Specific entropy relative to the datum of `s0` and unit pressure.
+    fn s(&self, t: F, p: F) -> F {
+        self.s0(t) - self.r() * p.ln()
+    }
+
+    /// Speed of sound.
+    fn speed_of_sound(&self, t: F) -> F {
+        (self.gamma(t) * self.r() * t).sqrt()
+    }
+}
+
+/// Gas with constant specific heats
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{CaloricallyPerfect, Gas};
+///
+/// let air = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// assert_eq!(air.cp(300.0), 1004.5000000000001);
+/// assert_eq!(air.gamma(1000.0), 1.4);
+/// assert_eq!(air.speed_of_sound(288.15), 340.2626485525556);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CaloricallyPerfect<F> {
+    /// Specific heat ratio.
+    pub gamma: F,
+    /// Specific gas constant.
+    pub r: F,
+}
+
+impl<F: Float> CaloricallyPerfect<F> {
+    /// Gas with a given specific heat ratio and specific gas constant.
+    pub fn new(gamma: F, r: F) -> Self {
+        validate::gamma(gamma);
+        Self { gamma, r }
+    }
+}
+
+impl<F: Float> Gas<F> for CaloricallyPerfect<F> {
+    fn r(&self) -> F {
+        self.r
+    }
+
+    fn cp(&self, _t: F) -> F {
+        self.gamma * self.r / (self.gamma - F::one())
+    }
+
+    fn h(&self, t: F) -> F {
+        self.cp(t) * t
+    }
+
+    fn s0(&self, t: F) -> F {
+        self.cp(t) * t.ln()
+    }
+
+    fn gamma(&self, _t: F) -> F {
+        self.gamma
+    }
+}
+
+/// Universal gas constant in J/(mol K).
+pub const UNIVERSAL_GAS_CONSTANT: f64 = 8.314462618;
+
+/// Ideal mixture of gases
+///
+/// Properties are mass-fraction weighted sums of the species properties, so
+/// temperature-dependent species give a temperature-dependent mixture. The
+/// species gas constants are taken to be in J/(kg K) when converting between
+/// mole and mass fractions.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{CaloricallyPerfect, Gas, GasMixture};
+///
+/// let n2 = CaloricallyPerfect::new(1.4_f64, 296.8);
+/// let o2 = CaloricallyPerfect::new(1.4, 259.8);
+/// let ar = CaloricallyPerfect::new(5.0 / 3.0, 208.1);
+/// let air = GasMixture::from_mole_fractions(&[(n2, 0.7808), (o2, 0.2095), (ar, 0.0093)]);
+///
+/// assert_eq!(air.r(), 287.09227727035085);
+/// assert_eq!(air.gamma(300.0), 1.4014941559224001);
+/// assert_eq!(air.molar_mass(), 0.028960941398539903);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct GasMixture<F, G> {
+    species: Vec<(G, F)>,
+}
+
+impl<F: Float, G: Gas<F> + Clone> GasMixture<F, G> {
+    /// Mixture from species and their mole fractions.
+    ///
+    /// The fractions are normalised by their sum.
+    pub fn from_mole_fractions(species: &[(G, F)]) -> Self {
+        // Mass fraction is proportional to mole fraction times molar mass,
+        // which is inversely proportional to the gas constant.
+        let mass: Vec<(G, F)> = species
+            .iter()
+            .map(|(gas, x)| (gas.clone(), *x / gas.r()))
+            .collect();
+        Self::from_mass_fractions(&mass)
+    }
+
+    /// Mixture from species and their mass fractions.
+    ///
+    /// The fractions are normalised by their sum.
+    pub fn from_mass_fractions(species: &[(G, F)]) -> Self {
+        let total = species.iter().fold(F::zero(), |acc, (_, y)| acc + *y);
+        Self {
+            species: species
+                .iter()
+                .map(|(gas, y)| (gas.clone(), *y / total))
+                .collect(),
+        }
+    }
+}
+
+impl<F: Float, G: Gas<F>> GasMixture<F, G> {
+    /// Species and their mass fractions.
+    pub fn species(&self) -> &[(G, F)] {
+        &self.species
+    }
+
+    /// Mole fractions of the species, in the order they were given.
+    pub fn mole_fractions(&self) -> Vec<F> {
+        let r = self.r();
+        self.species
+            .iter()
+            .map(|(gas, y)| *y * gas.r() / r)
+            .collect()
+    }
+
+    /// Mixture molar mass in kg/mol.
+    pub fn molar_mass(&self) -> F {
+        F::from(UNIVERSAL_GAS_CONSTANT).unwrap() / self.r()
+    }
+
+    fn sum(&self, f: impl Fn(&G) -> F) -> F {
+        self.species
+            .iter()
+            .fold(F::zero(), |acc, (gas, y)| acc + *y * f(gas))
+    }
+}
+
+impl<F: Float, G: Gas<F>> Gas<F> for GasMixture<F, G> {
+    fn r(&self) -> F {
+        self.sum(|gas| gas.r())
+    }
+
+    fn cp(&self, t: F) -> F {
+        self.sum(|gas| gas.cp(t))
+    }
+
+    fn h(&self, t: F) -> F {
+        self.sum(|gas| gas.h(t))
+    }
+
+    fn s0(&self, t: F) -> F {
+        self.sum(|gas| gas.s0(t))
+    }
+}
@@ -0,0 +1,132 @@
+//! Constant-property models of common working fluids
+//!
+//! Specific heat ratios are room-temperature values, except for steam and
+//! combustion products which are typical of their usual operating
+//! temperatures. Gas constants are in J/(kg K). For wide temperature ranges
+//! use a temperature-dependent model such as
+//! [`NasaPolynomial`](crate::NasaPolynomial) instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use comp_flow::{gases, Gas};
+//!
+//! let he = gases::helium::<f64>();
+//! assert_eq!(he.speed_of_sound(300.0), 1019.0927337588076);
+//! ```
+use crate::{CaloricallyPerfect, Gas, GasMixture};
+use num::Float;
+
+fn gas<F: Float>(gamma: f64, r: f64) -> CaloricallyPerfect<F> {
+    CaloricallyPerfect::new(F::from(gamma).unwrap(), F::from(r).unwrap())
+}
+
+/// Dry air, gamma = 1.4 and R = 287.05.
+pub fn air<F: Float>() -> CaloricallyPerfect<F> {
+    gas(1.4, 287.05)
+}
+
+/// Nitrogen, gamma = 1.4 and R = 296.8.
+pub fn nitrogen<F: Float>() -> CaloricallyPerfect<F> {
+    gas(1.4, 296.8)
+}
+
+/// Oxygen, gamma = 1.395 and R = 259.8.
+pub fn oxygen<F: Float>() -> CaloricallyPerfect<F> {
+    gas(1.395, 259.8)
+}
+
+/// Helium, gamma = 5/3 and R = 2077.1.
+pub fn helium<F: Float>() -> CaloricallyPerfect<F> {
+    gas(5.0 / 3.0, 2077.1)
+}
+
+/// Argon, gamma = 5/3 and R = 208.1.
+pub fn argon<F: Float>() -> CaloricallyPerfect<F> {
+    gas(5.0 / 3.0, 208.1)
+}
+
+/// Carbon dioxide, gamma = 1.289 and R = 188.9.
+pub fn carbon_dioxide<F: Float>() -> CaloricallyPerfect<F> {
+    gas(1.289, 188.9)
+}
+
+/// Hydrogen, gamma = 1.405 and R = 4124.2.
+pub fn hydrogen<F: Float>() -> CaloricallyPerfect<F> {
+    gas(1.405, 4124.2)
+}
+
+/// Superheated steam, gamma = 1.33 and R = 461.5.
+pub fn steam<F: Float>() -> CaloricallyPerfect<F> {
+    gas(1.33, 461.5)
+}
+
+/// Typical hydrocarbon-air combustion products, gamma = 1.33 and R = 287.
+pub fn combustion_products<F: Float>() -> CaloricallyPerfect<F> {
+    gas(1.33, 287.0)
+}
+
+/// Saturation vapour pressure of water over liquid water
+///
+/// Buck (1996) correlation, with temperature in K and pressure in Pa. Accurate
+/// to about 0.05% between -40 and 50 C.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::gases;
+///
+/// assert_eq!(gases::saturation_vapour_pressure(293.15_f64), 2338.339978450019);
+/// ```
+pub fn saturation_vapour_pressure<F: Float>(t: F) -> F {
+    let n = |x: f64| F::from(x).unwrap();
+    let tc = t - n(273.15);
+    n(611.21) * ((n(18.678) - tc / n(234.5)) * (tc / (n(257.14) + tc))).exp()
+}
+
+/// Moist air at a given temperature, pressure and relative humidity
+///
+/// Temperature in K, pressure in Pa and relative humidity as a fraction. The
+/// water vapour is mixed into dry air as [`steam`].
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::gases;
+///
+/// let humid = gases::humid_air(303.15_f64, 101325.0, 0.8);
+/// assert_eq!(humid.r, 290.733479395718);
+/// assert_eq!(humid.gamma, 1.397176217345871);
+/// ```
+pub fn humid_air<F: Float>(t: F, p: F, relative_humidity: F) -> CaloricallyPerfect<F> {
+    let x = relative_humidity * saturation_vapour_pressure(t) / p;
+    effective(GasMixture::from_mole_fractions(&[
+        (air(), F::one() - x),
+        (steam(), x),
+    ]))
+}
+
+/// Moist air with a given specific humidity
+///
+/// Specific humidity is the mass of water vapour per unit mass of moist air.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::gases;
+///
+/// let humid = gases::humid_air_from_specific_humidity(0.01_f64);
+/// assert_eq!(humid.r, 288.7945);
+/// ```
+pub fn humid_air_from_specific_humidity<F: Float>(q: F) -> CaloricallyPerfect<F> {
+    effective(GasMixture::from_mass_fractions(&[
+        (air(), F::one() - q),
+        (steam(), q),
+    ]))
+}
+
+/// Constant-property gas with the mixture's gamma and R.
+fn effective<F: Float>(mixture: GasMixture<F, CaloricallyPerfect<F>>) -> CaloricallyPerfect<F> {
+    // Both components have constant properties, so any temperature will do.
+    CaloricallyPerfect::new(mixture.gamma(F::one()), mixture.r())
+}
@@ -0,0 +1,210 @@
+//! Aerodynamic heating: recovery temperatures and stagnation point heat flux
+use crate::{probe_stagnation_point, validate, Gas, Transport};
+use num::Float;
+
+/// Boundary-layer temperature recovery factor
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::RecoveryFactor;
+///
+/// assert_eq!(RecoveryFactor::Laminar.value(0.71_f64), 0.8426149773176358);
+/// assert_eq!(RecoveryFactor::Turbulent.value(0.71_f64), 0.8921121404456347);
+/// assert_eq!(RecoveryFactor::Fixed(0.9).value(0.71_f64), 0.9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecoveryFactor<F> {
+    /// Laminar boundary layer, r = Pr^(1/2).
+    Laminar,
+    /// Turbulent boundary layer, r = Pr^(1/3).
+    Turbulent,
+    /// A given recovery factor, e.g. from probe calibration.
+    Fixed(F),
+}
+
+impl<F: Float> RecoveryFactor<F> {
+    /// Recovery factor for a given Prandtl number.
+    pub fn value(&self, prandtl: F) -> F {
+        match *self {
+            RecoveryFactor::Laminar => prandtl.sqrt(),
+            RecoveryFactor::Turbulent => prandtl.cbrt(),
+            RecoveryFactor::Fixed(r) => r,
+        }
+    }
+}
+
+/// Adiabatic wall temperature
+///
+/// T_aw = T (1 + r (gamma - 1)/2 M^2) for static temperature `t`.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{adiabatic_wall_temperature, RecoveryFactor};
+///
+/// let t_aw = adiabatic_wall_temperature(220.0_f64, 2.0, 1.4, RecoveryFactor::Turbulent, 0.71);
+/// assert_eq!(t_aw, 377.0117367184317);
+/// ```
+pub fn adiabatic_wall_temperature<F: Float>(
+    t: F,
+    mach: F,
+    gamma: F,
+    recovery: RecoveryFactor<F>,
+    prandtl: F,
+) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    let r = recovery.value(prandtl);
+    t * (F::one() + r * (gamma - F::one()) / F::from(2.).unwrap() * mach.powi(2))
+}
+
+/// Total temperature from the recovered temperature measured by a probe
+///
+/// The probe reads T_r = T (1 + r (gamma - 1)/2 M^2), short of the total
+/// temperature unless the recovery factor is one.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{probe_t0, RecoveryFactor};
+///
+/// let t0 = probe_t0(300.0_f64, 0.8, 1.4, RecoveryFactor::Fixed(0.98), 0.71);
+/// assert_eq!(t0, 300.6823997725334);
+/// ```
+pub fn probe_t0<F: Float>(
+    t_recovered: F,
+    mach: F,
+    gamma: F,
+    recovery: RecoveryFactor<F>,
+    prandtl: F,
+) -> F {
+    let t = probe_t(t_recovered, mach, gamma, recovery, prandtl);
+    t * (F::one() + (gamma - F::one()) / F::from(2.).unwrap() * mach.powi(2))
+}
+
+/// Static temperature from the recovered temperature measured by a probe
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{adiabatic_wall_temperature, probe_t, RecoveryFactor};
+///
+/// let recovery = RecoveryFactor::Fixed(0.98);
+/// let t_r = adiabatic_wall_temperature(250.0_f64, 0.8, 1.4, recovery, 0.71);
+/// assert!((probe_t(t_r, 0.8, 1.4, recovery, 0.71) - 250.0).abs() < 1e-12);
+/// ```
+pub fn probe_t<F: Float>(
+    t_recovered: F,
+    mach: F,
+    gamma: F,
+    recovery: RecoveryFactor<F>,
+    prandtl: F,
+) -> F {
+    t_recovered / adiabatic_wall_temperature(F::one(), mach, gamma, recovery, prandtl)
+}
+
+/// Mach number from the ratio of recovered to static temperature
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{mach_from_probe_tr_t, RecoveryFactor};
+///
+/// assert_eq!(mach_from_probe_tr_t(1.8_f64, 1.4, RecoveryFactor::Fixed(1.0), 0.71), 2.0);
+/// ```
+pub fn mach_from_probe_tr_t<F: Float>(
+    tr_t: F,
+    gamma: F,
+    recovery: RecoveryFactor<F>,
+    prandtl: F,
+) -> F {
+    validate::gamma(gamma);
+    let r = recovery.value(prandtl);
+    (F::from(2.).unwrap() * (tr_t - F::one()) / (r * (gamma - F::one()))).sqrt()
+}
+
+/// Eckert reference temperature for compressible boundary layers
+///
+/// T* = T + 0.5 (T_w - T) + 0.22 (T_aw - T), with the adiabatic wall
+/// temperature from the given recovery factor. Evaluating incompressible
+/// skin friction and heat transfer correlations with properties at T*
+/// accounts for compressibility.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{reference_temperature, RecoveryFactor};
+///
+/// let t_star = reference_temperature(220.0_f64, 300.0, 2.0, 1.4, RecoveryFactor::Turbulent, 0.71);
+/// assert_eq!(t_star, 294.542582078055);
+/// ```
+pub fn reference_temperature<F: Float>(
+    t: F,
+    t_wall: F,
+    mach: F,
+    gamma: F,
+    recovery: RecoveryFactor<F>,
+    prandtl: F,
+) -> F {
+    let t_aw = adiabatic_wall_temperature(t, mach, gamma, recovery, prandtl);
+    t + F::from(0.5).unwrap() * (t_wall - t) + F::from(0.22).unwrap() * (t_aw - t)
+}
+
+/// Stagnation point convective heat flux from the Sutton-Graves correlation
+///
+/// q = k sqrt(rho / R_n) V^3 in SI units, with k = 1.7415e-4 for air. Suitable
+/// for entry velocities where the flow behind the shock is in chemical
+/// equilibrium.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::sutton_graves_heat_flux;
+///
+/// assert_eq!(sutton_graves_heat_flux(1e-4_f64, 7000.0, 1.0), 597334.5000000001);
+/// ```
+pub fn sutton_graves_heat_flux<F: Float>(rho: F, velocity: F, nose_radius: F) -> F {
+    F::from(1.7415e-4).unwrap() * (rho / nose_radius).sqrt() * velocity.powi(3)
+}
+
+/// Stagnation point convective heat flux from the Fay-Riddell relation
+///
+/// q = 0.763 Pr^-0.6 (rho_w mu_w)^0.1 (rho_e mu_e)^0.4 (h0 - h_w) sqrt(du_e/dx)
+///
+/// for a nose of radius `nose_radius` and wall temperature `t_wall`, in a
+/// freestream of given Mach number, static pressure and temperature. The
+/// boundary-layer edge state is the stagnation state behind the bow shock,
+/// the velocity gradient is from Newtonian theory, and dissociation is
+/// neglected, so this is the frozen perfect-gas form.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{fay_riddell_heat_flux, CaloricallyPerfect, Transport};
+///
+/// let air = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// let q = fay_riddell_heat_flux(&air, &Transport::air(), 8.0, 1000.0, 230.0, 0.1, 300.0);
+/// assert_eq!(q, 921311.7281645476);
+/// ```
+pub fn fay_riddell_heat_flux<F: Float, G: Gas<F>>(
+    gas: &G,
+    transport: &Transport<F>,
+    mach: F,
+    p: F,
+    t: F,
+    nose_radius: F,
+    t_wall: F,
+) -> F {
+    validate::supersonic(mach);
+    let n = |x: f64| F::from(x).unwrap();
+    let edge = probe_stagnation_point(gas, mach, p, t);
+    let rho_w = edge.p / (gas.r() * t_wall);
+    let velocity_gradient = (n(2.) * (edge.p - p) / edge.rho).sqrt() / nose_radius;
+    n(0.763)
+        * transport.prandtl.powf(n(-0.6))
+        * (rho_w * transport.mu(t_wall)).powf(n(0.1))
+        * (edge.rho * transport.mu(edge.t)).powf(n(0.4))
+        * (gas.h(edge.t) - gas.h(t_wall))
+        * velocity_gradient.sqrt()
+}
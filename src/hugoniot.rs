@@ -0,0 +1,93 @@
+//! Rankine-Hugoniot curve and Rayleigh line
+//!
+//! In the plane of specific volume ratio v2/v1 and pressure ratio p2/p1, the
+//! states reachable from a given upstream state by a shock lie on the
+//! Hugoniot curve, and the states with the same mass flux lie on a straight
+//! Rayleigh line whose slope is set by the upstream Mach number. A shock
+//! connects the upstream state to where the two intersect.
+use crate::validate;
+use num::Float;
+
+/// Pressure ratio on the Hugoniot curve for a given specific volume ratio
+/// v2/v1
+///
+/// Grows without bound as v2/v1 approaches the limiting compression
+/// (gamma - 1)/(gamma + 1).
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{hugoniot_p2_p1, normal_p2_p1, normal_rho2_rho1};
+///
+/// assert_eq!(hugoniot_p2_p1(1.0_f64, 1.4), 1.0);
+/// let v2_v1 = 1.0 / normal_rho2_rho1(2.0_f64, 1.4);
+/// assert!((hugoniot_p2_p1(v2_v1, 1.4) - normal_p2_p1(2.0, 1.4)).abs() < 1e-12);
+/// ```
+pub fn hugoniot_p2_p1<F: Float>(v2_v1: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    let gp1 = gamma + F::one();
+    let gm1 = gamma - F::one();
+    (gp1 - gm1 * v2_v1) / (gp1 * v2_v1 - gm1)
+}
+
+/// Pressure ratio on the Rayleigh line through the upstream state for a given
+/// specific volume ratio v2/v1 and upstream Mach number
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::rayleigh_line_p2_p1;
+///
+/// assert_eq!(rayleigh_line_p2_p1(1.0_f64, 2.0, 1.4), 1.0);
+/// assert_eq!(rayleigh_line_p2_p1(0.5_f64, 2.0, 1.4), 3.8);
+/// ```
+pub fn rayleigh_line_p2_p1<F: Float>(v2_v1: F, mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    F::one() + gamma * mach.powi(2) * (F::one() - v2_v1)
+}
+
+/// Points `(v2/v1, p2/p1)` along the Hugoniot curve
+///
+/// The `n` points are evenly spaced in v2/v1 from 1 down to `v_end`, which
+/// must exceed the limiting compression (gamma - 1)/(gamma + 1).
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::hugoniot_curve;
+///
+/// let curve = hugoniot_curve(1.4_f64, 0.25, 4);
+/// assert_eq!(curve[0], (1.0, 1.0));
+/// assert_eq!(curve[3], (0.25, 11.499999999999995));
+/// ```
+pub fn hugoniot_curve<F: Float>(gamma: F, v_end: F, n: usize) -> Vec<(F, F)> {
+    volumes(v_end, n)
+        .map(|v| (v, hugoniot_p2_p1(v, gamma)))
+        .collect()
+}
+
+/// Points `(v2/v1, p2/p1)` along the Rayleigh line for an upstream Mach
+/// number, spaced as in [`hugoniot_curve`]
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{hugoniot_curve, rayleigh_line};
+///
+/// let line = rayleigh_line(2.0_f64, 1.4, 0.25, 4);
+/// let curve = hugoniot_curve(1.4, 0.25, 4);
+/// assert_eq!(line[0], curve[0]);
+/// assert_eq!(line[3], (0.25, 5.199999999999999));
+/// ```
+pub fn rayleigh_line<F: Float>(mach: F, gamma: F, v_end: F, n: usize) -> Vec<(F, F)> {
+    volumes(v_end, n)
+        .map(|v| (v, rayleigh_line_p2_p1(v, mach, gamma)))
+        .collect()
+}
+
+/// `n` evenly spaced volume ratios from 1 to `v_end`.
+fn volumes<F: Float>(v_end: F, n: usize) -> impl Iterator<Item = F> {
+    let steps = F::from(n.max(2) - 1).unwrap();
+    (0..n).map(move |i| F::one() + (v_end - F::one()) * F::from(i).unwrap() / steps)
+}
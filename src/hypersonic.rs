@@ -0,0 +1,312 @@
+//! Engineering methods for hypersonic surface pressures
+//!
+//! Surface inclination `delta` is the angle between the local surface and the
+//! freestream, so the stagnation point of a blunt body has `delta = pi/2`.
+use crate::{mach_from_pm_angle, mach_to, mach_to_p_p0, mach_to_pitot_p02_p1, validate};
+use eqsolver::single_variable::FDNewton;
+use num::Float;
+
+/// Pressure coefficient from Newtonian impact theory
+///
+/// Cp = 2 sin^2 delta, the limit of infinite Mach number and gamma
+/// approaching one.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::cp_newtonian;
+///
+/// assert_eq!(cp_newtonian(std::f64::consts::FRAC_PI_2), 2.0);
+/// assert_eq!(cp_newtonian(0.2_f64), 0.07893900599711491);
+/// ```
+pub fn cp_newtonian<F: Float>(delta: F) -> F {
+    F::from(2.).unwrap() * delta.sin().powi(2)
+}
+
+/// Pressure coefficient from modified Newtonian theory
+///
+/// Lees' modification, Cp = Cp_max sin^2 delta, where Cp_max is the
+/// stagnation pressure coefficient behind a normal shock.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::cp_modified_newtonian;
+///
+/// let half_pi = std::f64::consts::FRAC_PI_2;
+/// assert_eq!(cp_modified_newtonian(half_pi, 10.0_f64, 1.4), 1.831670977387537);
+/// assert_eq!(cp_modified_newtonian(0.2, 10.0_f64, 1.4), 0.07229514313436806);
+/// ```
+pub fn cp_modified_newtonian<F: Float>(delta: F, mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let cp_max = (mach_to_pitot_p02_p1(mach, gamma) - F::one())
+        / (F::from(0.5).unwrap() * gamma * mach.powi(2));
+    cp_max * delta.sin().powi(2)
+}
+
+/// Pressure coefficient on a blunt body from modified Newtonian theory
+/// matched to a Prandtl-Meyer expansion
+///
+/// Modified Newtonian theory is used from the stagnation point down to the
+/// inclination at which its pressure and pressure gradient match those of a
+/// Prandtl-Meyer expansion; below that inclination the flow is expanded
+/// along the surface from the matching point. This removes the underestimated
+/// pressures of pure Newtonian theory near the shoulder.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{cp_modified_newtonian, newtonian_pm_cp};
+///
+/// let (mach, gamma) = (10.0_f64, 1.4);
+/// let half_pi = std::f64::consts::FRAC_PI_2;
+/// let stag = newtonian_pm_cp(mach, gamma, half_pi);
+/// assert!((stag - cp_modified_newtonian(half_pi, mach, gamma)).abs() < 1e-12);
+///
+/// assert_eq!(newtonian_pm_cp(mach, gamma, 0.2), 0.16322089017614907);
+/// ```
+pub fn newtonian_pm_cp<F: Float>(mach: F, gamma: F, delta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let p_p02 = newtonian_pm_p_p02(mach, gamma, delta);
+    let p02_p = mach_to_pitot_p02_p1(mach, gamma);
+    (p_p02 * p02_p - F::one()) / (F::from(0.5).unwrap() * gamma * mach.powi(2))
+}
+
+/// Pressure coefficient on a sphere-cone from modified Newtonian theory
+/// matched to a Prandtl-Meyer expansion
+///
+/// `s` is the surface distance from the stagnation point divided by the nose
+/// radius and `half_angle` the cone half-angle. On the cone the inclination
+/// is constant, so the pressure is that of the sphere at the tangency point.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{newtonian_pm_cp, sphere_cone_cp};
+///
+/// let half_angle = 0.3_f64;
+/// let cone = sphere_cone_cp(10.0, 1.4, half_angle, 3.0);
+/// assert_eq!(cone, newtonian_pm_cp(10.0, 1.4, half_angle));
+/// ```
+pub fn sphere_cone_cp<F: Float>(mach: F, gamma: F, half_angle: F, s: F) -> F {
+    validate::deflection(half_angle);
+    let half_pi = F::from(std::f64::consts::FRAC_PI_2).unwrap();
+    newtonian_pm_cp(mach, gamma, (half_pi - s).max(half_angle))
+}
+
+/// Surface pressure relative to the stagnation pressure behind the shock.
+fn newtonian_pm_p_p02<F: Float>(mach: F, gamma: F, delta: F) -> F {
+    let p_inf = F::one() / mach_to::pitot_p02_p1(mach, gamma);
+    match matching_point(gamma, p_inf) {
+        Some((delta_q, mach_q)) if delta < delta_q => {
+            let nu = mach_to::pm_angle(mach_q, gamma) + delta_q - delta;
+            mach_to_p_p0(mach_from_pm_angle(nu, gamma), gamma)
+        }
+        _ => modified_newtonian_p_p02(delta, p_inf),
+    }
+}
+
+/// Modified Newtonian pressure, p/p02 = sin^2 delta + (p_inf/p02) cos^2 delta.
+fn modified_newtonian_p_p02<F: Float>(delta: F, p_inf_p02: F) -> F {
+    delta.sin().powi(2) + p_inf_p02 * delta.cos().powi(2)
+}
+
+/// Inclination and local Mach number where the Newtonian and Prandtl-Meyer
+/// pressure gradients match, if they do.
+fn matching_point<F: Float>(gamma: F, p_inf_p02: F) -> Option<(F, F)> {
+    let delta = |mach: F| {
+        let sin2 = (mach_to::p_p0(mach, gamma) - p_inf_p02) / (F::one() - p_inf_p02);
+        sin2.sqrt().asin()
+    };
+    let f = |mach: F| {
+        let p_p02 = mach_to::p_p0(mach, gamma);
+        (F::one() - p_inf_p02) * (F::from(2.).unwrap() * delta(mach)).sin()
+            - gamma * mach.powi(2) * p_p02 / (mach.powi(2) - F::one()).sqrt()
+    };
+    let mach = FDNewton::new(f).solve(F::from(1.3).unwrap()).ok()?;
+    let delta_q = delta(mach);
+    (mach > F::one() && delta_q.is_finite()).then_some((delta_q, mach))
+}
+
+/// Wave angle on a wedge from hypersonic small-disturbance theory
+///
+/// beta/theta = (gamma + 1)/4 + sqrt(((gamma + 1)/4)^2 + 1/K^2), with the
+/// similarity parameter K = M theta. Valid for slender wedges at high Mach
+/// number.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{hypersonic_wedge_beta, oblique_beta};
+///
+/// let (mach, theta) = (10.0_f64, 0.1);
+/// assert_eq!(hypersonic_wedge_beta(mach, 1.4, theta), 0.17661903789690603);
+/// assert_eq!(oblique_beta(mach, 1.4, theta), 0.17767539894233614);
+/// ```
+pub fn hypersonic_wedge_beta<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let k = mach * theta;
+    let c = (gamma + F::one()) / F::from(4.).unwrap();
+    theta * (c + (c.powi(2) + k.powi(-2)).sqrt())
+}
+
+/// Pressure coefficient on a wedge from hypersonic small-disturbance theory
+///
+/// Cp/theta^2 = (gamma + 1)/2 + sqrt(((gamma + 1)/2)^2 + 4/K^2).
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::hypersonic_wedge_cp;
+///
+/// assert_eq!(hypersonic_wedge_cp(10.0_f64, 1.4, 0.1), 0.03532380757938121);
+/// ```
+pub fn hypersonic_wedge_cp<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let k = mach * theta;
+    let c = (gamma + F::one()) / F::from(2.).unwrap();
+    theta.powi(2) * (c + (c.powi(2) + F::from(4.).unwrap() / k.powi(2)).sqrt())
+}
+
+/// Pressure coefficient on a cone from hypersonic small-disturbance theory
+///
+/// Rasmussen's approximation,
+/// Cp/theta^2 = 1 + ((gamma + 1)K^2 + 2)/((gamma - 1)K^2 + 2) ln((gamma + 1)/2 + 1/K^2),
+/// with `theta` the cone half-angle.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::hypersonic_cone_cp;
+///
+/// assert_eq!(hypersonic_cone_cp(10.0_f64, 1.4, 0.1), 0.024455051606678296);
+/// ```
+pub fn hypersonic_cone_cp<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let two = F::from(2.).unwrap();
+    let k2 = (mach * theta).powi(2);
+    let ratio = ((gamma + F::one()) * k2 + two) / ((gamma - F::one()) * k2 + two);
+    theta.powi(2) * (F::one() + ratio * ((gamma + F::one()) / two + k2.recip()).ln())
+}
+
+/// Static pressure ratio across an oblique shock in the limit of infinite
+/// Mach number, 2 gamma / (gamma + 1) M^2 sin^2 beta
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::hypersonic_limit_p2_p1;
+///
+/// assert_eq!(hypersonic_limit_p2_p1(20.0_f64, 1.4, 0.3), 40.755023187741735);
+/// ```
+pub fn hypersonic_limit_p2_p1<F: Float>(mach: F, gamma: F, beta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    F::from(2.).unwrap() * gamma / (gamma + F::one()) * (mach * beta.sin()).powi(2)
+}
+
+/// Density ratio across an oblique shock in the limit of infinite Mach
+/// number, (gamma + 1)/(gamma - 1)
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::hypersonic_limit_rho2_rho1;
+///
+/// assert_eq!(hypersonic_limit_rho2_rho1(1.4_f64), 6.000000000000001);
+/// ```
+pub fn hypersonic_limit_rho2_rho1<F: Float>(gamma: F) -> F {
+    validate::gamma(gamma);
+    (gamma + F::one()) / (gamma - F::one())
+}
+
+/// Static temperature ratio across an oblique shock in the limit of infinite
+/// Mach number, 2 gamma (gamma - 1)/(gamma + 1)^2 M^2 sin^2 beta
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::hypersonic_limit_t2_t1;
+///
+/// assert_eq!(hypersonic_limit_t2_t1(20.0_f64, 1.4, 0.3), 6.7925038646236215);
+/// ```
+pub fn hypersonic_limit_t2_t1<F: Float>(mach: F, gamma: F, beta: F) -> F {
+    hypersonic_limit_p2_p1(mach, gamma, beta) / hypersonic_limit_rho2_rho1(gamma)
+}
+
+/// Blunt body shape for the bow shock correlations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BluntBody {
+    /// Sphere, or sphere-capped nose.
+    Sphere,
+    /// Circular cylinder in cross flow, or cylinder-wedge leading edge.
+    Cylinder,
+}
+
+/// Bow shock stand-off distance divided by the nose radius, from Billig's
+/// correlation
+///
+/// Correlated for gamma = 1.4 from Mach 1.4 to about 25.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{billig_standoff, BluntBody};
+///
+/// assert_eq!(billig_standoff(5.0_f64, BluntBody::Sphere), 0.16278733085835453);
+/// assert_eq!(billig_standoff(5.0_f64, BluntBody::Cylinder), 0.4652790669096873);
+/// ```
+pub fn billig_standoff<F: Float>(mach: F, body: BluntBody) -> F {
+    validate::supersonic(mach);
+    let n = |x: f64| F::from(x).unwrap();
+    match body {
+        BluntBody::Sphere => n(0.143) * (n(3.24) / mach.powi(2)).exp(),
+        BluntBody::Cylinder => n(0.386) * (n(4.67) / mach.powi(2)).exp(),
+    }
+}
+
+/// Bow shock radius of curvature at the vertex divided by the nose radius,
+/// from Billig's correlation
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{billig_shock_radius, BluntBody};
+///
+/// assert_eq!(billig_shock_radius(5.0_f64, BluntBody::Sphere), 1.2661328475016362);
+/// ```
+pub fn billig_shock_radius<F: Float>(mach: F, body: BluntBody) -> F {
+    validate::supersonic(mach);
+    let n = |x: f64| F::from(x).unwrap();
+    let excess = mach - F::one();
+    match body {
+        BluntBody::Sphere => n(1.143) * (n(0.54) / excess.powf(n(1.2))).exp(),
+        BluntBody::Cylinder => n(1.386) * (n(1.8) / excess.powf(n(0.75))).exp(),
+    }
+}
+
+/// Axial distance of the bow shock behind its vertex at a lateral distance
+/// `y`, both divided by the nose radius, from Billig's shock shape
+///
+/// The shock is a hyperbola through the vertex with the correlated radius of
+/// curvature, asymptotic to the freestream Mach angle.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{billig_shock_shape, BluntBody};
+///
+/// let body = BluntBody::Sphere;
+/// assert_eq!(billig_shock_shape(5.0_f64, body, 0.0), 0.0);
+/// assert_eq!(billig_shock_shape(5.0_f64, body, 2.0), 1.540561584016111);
+/// ```
+pub fn billig_shock_shape<F: Float>(mach: F, body: BluntBody, y: F) -> F {
+    let rc = billig_shock_radius(mach, body);
+    let tan_mu = (mach.powi(2) - F::one()).sqrt().recip();
+    rc / tan_mu.powi(2) * ((F::one() + (y * tan_mu / rc).powi(2)).sqrt() - F::one())
+}
@@ -0,0 +1,173 @@
+//! Incompressible limit of the pitot and dynamic pressure relations
+//!
+//! At low Mach number the impact pressure qc = p0 - p approaches the
+//! Bernoulli dynamic pressure rho V^2 / 2. Evaluating qc as p (p0/p - 1)
+//! subtracts two nearly equal numbers and loses most of its digits by
+//! M = 0.05, so the functions here write the compressible relations as the
+//! incompressible result times a correction factor that tends to one. The
+//! factors use `exp_m1` and `ln_1p` and stay accurate down to M = 0.
+//!
+//! The incompressible formulas alone are within 1% of the compressible ones
+//! below about M = 0.2 and within 2.3% at the conventional crossover of
+//! [`INCOMPRESSIBLE_CROSSOVER_MACH`]; [`incompressible_crossover_mach`] gives
+//! the limit for any other tolerance.
+use crate::{mach_from_pitot, mach_to_pitot_p02_p1, validate};
+use eqsolver::single_variable::FDNewton;
+use num::Float;
+
+/// Mach number below which flow is conventionally treated as incompressible
+pub const INCOMPRESSIBLE_CROSSOVER_MACH: f64 = 0.3;
+
+/// Bernoulli dynamic pressure rho V^2 / 2
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::bernoulli_dynamic_pressure;
+///
+/// assert_eq!(bernoulli_dynamic_pressure(1.225_f64, 20.0), 245.00000000000003);
+/// ```
+pub fn bernoulli_dynamic_pressure<F: Float>(rho: F, velocity: F) -> F {
+    F::from(0.5).unwrap() * rho * velocity.powi(2)
+}
+
+/// Velocity from impact pressure by the incompressible pitot formula
+/// V = sqrt(2 qc / rho)
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::incompressible_pitot_velocity;
+///
+/// assert_eq!(incompressible_pitot_velocity(245.0_f64, 1.225), 20.0);
+/// ```
+pub fn incompressible_pitot_velocity<F: Float>(qc: F, rho: F) -> F {
+    (F::from(2.).unwrap() * qc / rho).sqrt()
+}
+
+/// Ratio of impact pressure to dynamic pressure qc/q for a given Mach number
+///
+/// This is the factor by which the incompressible pitot relation
+/// underestimates the impact pressure. It tends to 1 + M^2 / 4 at low Mach
+/// number and uses the Rayleigh pitot formula above Mach 1.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::impact_pressure_factor;
+///
+/// assert_eq!(impact_pressure_factor(0.0_f64, 1.4), 1.0);
+/// assert_eq!(impact_pressure_factor(0.05_f64, 1.4), 1.0006251562597652);
+/// assert_eq!(impact_pressure_factor(0.3_f64, 1.4), 1.0227029548085418);
+/// assert_eq!(impact_pressure_factor(2.0_f64, 1.4), 1.657300290294042);
+/// ```
+pub fn impact_pressure_factor<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    let q_p = F::from(0.5).unwrap() * gamma * mach.powi(2);
+    if mach == F::zero() {
+        F::one()
+    } else if mach <= F::one() {
+        impact_pressure_ratio(mach, gamma) / q_p
+    } else {
+        (mach_to_pitot_p02_p1(mach, gamma) - F::one()) / q_p
+    }
+}
+
+/// Ratio of impact pressure to static pressure qc/p for a given Mach number
+///
+/// Equal to p0/p - 1 below Mach 1 and to the Rayleigh pitot ratio less one
+/// above it, but evaluated without cancellation at low Mach number.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{impact_pressure_ratio, mach_to_p_p0};
+///
+/// assert_eq!(impact_pressure_ratio(0.5_f64, 1.4), 0.186212638044398);
+/// assert_eq!(impact_pressure_ratio(1e-4_f64, 1.4), 7.000000017500001e-9);
+/// assert_eq!(1.0 / mach_to_p_p0(1e-4_f64, 1.4) - 1.0, 6.999999913048782e-9);
+/// ```
+pub fn impact_pressure_ratio<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    if mach <= F::one() {
+        let half = F::from(0.5).unwrap();
+        let gm1 = gamma - F::one();
+        (gamma / gm1 * (half * gm1 * mach.powi(2)).ln_1p()).exp_m1()
+    } else {
+        mach_to_pitot_p02_p1(mach, gamma) - F::one()
+    }
+}
+
+/// Mach number for a given ratio of impact pressure to static pressure qc/p
+///
+/// The inverse of [`impact_pressure_ratio`], accurate for the small ratios
+/// seen at low speed where `mach_from_pitot(1.0 + qc_p, gamma)` would round
+/// the ratio away.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_from_impact_pressure_ratio;
+///
+/// assert_eq!(mach_from_impact_pressure_ratio(0.1862126380443982_f64, 1.4), 0.5000000000000002);
+/// assert_eq!(mach_from_impact_pressure_ratio(1e-9_f64, 1.4), 3.779644729417336e-5);
+/// assert_eq!(mach_from_impact_pressure_ratio(4.640440812823317_f64, 1.4), 2.0);
+/// ```
+pub fn mach_from_impact_pressure_ratio<F: Float>(qc_p: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    let gm1 = gamma - F::one();
+    let sonic = impact_pressure_ratio(F::one(), gamma);
+    if qc_p <= sonic {
+        let two = F::from(2.).unwrap();
+        (two / gm1 * (gm1 / gamma * qc_p.ln_1p()).exp_m1()).sqrt()
+    } else {
+        mach_from_pitot(F::one() + qc_p, gamma)
+    }
+}
+
+/// Ratio of the subsonic compressible pitot velocity to the incompressible one
+///
+/// Multiplying [`incompressible_pitot_velocity`] by this factor gives the
+/// compressible velocity from impact pressure `qc`, static pressure `p` and
+/// density `rho`, so the two formulas blend smoothly with no switch at a
+/// crossover Mach number. The factor tends to one as qc/p goes to zero.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{incompressible_pitot_velocity, pitot_velocity_factor};
+///
+/// let (qc, p, rho) = (18867.995549848652_f64, 101325.0, 1.225);
+/// let v = incompressible_pitot_velocity(qc, rho) * pitot_velocity_factor(qc / p, 1.4);
+/// assert_eq!(v, 170.14699527173568);
+/// assert_eq!(pitot_velocity_factor(0.0_f64, 1.4), 1.0);
+/// ```
+pub fn pitot_velocity_factor<F: Float>(qc_p: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    if qc_p == F::zero() {
+        return F::one();
+    }
+    let exponent = (gamma - F::one()) / gamma;
+    ((exponent * qc_p.ln_1p()).exp_m1() / (exponent * qc_p)).sqrt()
+}
+
+/// Highest Mach number at which the incompressible pitot formula is within
+/// a relative `tolerance` of the compressible impact pressure
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::incompressible_crossover_mach;
+///
+/// assert_eq!(incompressible_crossover_mach(1.4_f64, 0.01), 0.19960238128710278);
+/// assert_eq!(incompressible_crossover_mach(1.4_f64, 0.05), 0.442870571008855);
+/// ```
+pub fn incompressible_crossover_mach<F: Float>(gamma: F, tolerance: F) -> F {
+    validate::gamma(gamma);
+    let f = |m: F| impact_pressure_factor(m.abs(), gamma) - F::one() - tolerance;
+    // qc/q is 1 + M^2 / 4 to leading order.
+    let x0 = F::from(2.).unwrap() * tolerance.sqrt();
+    FDNewton::new(f).solve(x0).map(F::abs).unwrap_or(F::nan())
+}
@@ -0,0 +1,211 @@
+//! One-dimensional model of a started supersonic inlet
+//!
+//! The inlet is a convergent-divergent duct. Once started, the captured flow
+//! is compressed isentropically to a supersonic throat and a terminal normal
+//! shock stands in the divergent diffuser, at the position that matches the
+//! back pressure. Raising the back pressure pushes the shock upstream; once it
+//! would need to stand ahead of the throat it is expelled and the inlet
+//! unstarts. Area ratios are relative to the throat area.
+//!
+//! [`InletRecovery`] gives the ram recovery of a whole inlet at several
+//! levels of fidelity, from the MIL-E-5008B specification curve to an
+//! optimised shock system.
+use crate::{
+    mach_from_a_ac, mach_to_a_ac, mach_to_p_p0, normal_mach2, normal_p02_p01, oswatitsch_inlet,
+    validate,
+};
+use num::Float;
+
+/// Convergent-divergent inlet geometry
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::Inlet;
+///
+/// let inlet = Inlet::new(1.3_f64, 1.8);
+/// assert_eq!(inlet.throat_mach(2.0, 1.4), Some(1.6566723735936615));
+/// assert_eq!(inlet.max_back_pressure(2.0, 1.4), Some(6.422188931748573));
+///
+/// // Too much contraction cannot pass the captured flow.
+/// assert_eq!(Inlet::new(2.0_f64, 1.8).throat_mach(2.0, 1.4), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Inlet<F> {
+    /// Capture to throat area ratio.
+    pub contraction_ratio: F,
+    /// Diffuser exit to throat area ratio.
+    pub exit_area_ratio: F,
+}
+
+impl<F: Float> Inlet<F> {
+    /// Inlet with given capture and exit area ratios.
+    pub fn new(contraction_ratio: F, exit_area_ratio: F) -> Self {
+        Self {
+            contraction_ratio,
+            exit_area_ratio,
+        }
+    }
+
+    /// Throat Mach number of the started inlet at a freestream Mach number,
+    /// or `None` if the throat is too small to pass the captured flow.
+    pub fn throat_mach(&self, mach: F, gamma: F) -> Option<F> {
+        validate::supersonic(mach);
+        validate::gamma(gamma);
+        let a_ac = mach_to_a_ac(mach, gamma) / self.contraction_ratio;
+        (a_ac >= F::one()).then(|| mach_from_a_ac(a_ac, gamma, true))
+    }
+
+    /// Largest back pressure, relative to freestream static pressure, that
+    /// keeps the terminal shock in the diffuser
+    ///
+    /// The limit has the shock standing at the throat, followed by subsonic
+    /// diffusion to the exit. `None` if the inlet cannot be started.
+    pub fn max_back_pressure(&self, mach: F, gamma: F) -> Option<F> {
+        let throat = self.throat_mach(mach, gamma)?;
+        let p02_p01 = normal_p02_p01(throat, gamma);
+        // The sonic area grows by the stagnation pressure loss of the shock.
+        let a_ac_exit = self.exit_area_ratio * mach_to_a_ac(throat, gamma) * p02_p01;
+        let exit = mach_from_a_ac(a_ac_exit, gamma, false);
+        Some(p02_p01 * mach_to_p_p0(exit, gamma) / mach_to_p_p0(mach, gamma))
+    }
+
+    /// Unstart margin (p_max - p_b)/p_b for a back pressure ratio `pb_p`
+    /// relative to freestream static pressure
+    ///
+    /// Negative margins mean the terminal shock would be expelled. `None` if
+    /// the inlet cannot be started.
+    pub fn unstart_margin(&self, mach: F, gamma: F, pb_p: F) -> Option<F> {
+        Some(self.max_back_pressure(mach, gamma)? / pb_p - F::one())
+    }
+
+    /// Whether the inlet starts by itself when accelerated to a freestream
+    /// Mach number, see [`kantrowitz_limit`].
+    pub fn self_starts(&self, mach: F, gamma: F) -> bool {
+        self_starts(mach, gamma, self.contraction_ratio.recip())
+    }
+}
+
+/// Largest capture to throat area ratio that still self-starts
+///
+/// Before starting, a normal shock stands at the inlet entrance and the
+/// throat must pass the subsonic flow behind it. The limit is therefore the
+/// critical area ratio at the Mach number behind a normal shock, below the
+/// isentropic contraction [`mach_to_a_ac`] that would slow the started flow
+/// to sonic at the throat.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{kantrowitz_limit, mach_to_a_ac};
+///
+/// assert_eq!(kantrowitz_limit(2.0_f64, 1.4), 1.216474641255508);
+/// assert!(kantrowitz_limit(2.0_f64, 1.4) < mach_to_a_ac(2.0, 1.4));
+/// ```
+pub fn kantrowitz_limit<F: Float>(mach: F, gamma: F) -> F {
+    mach_to_a_ac(normal_mach2(mach, gamma), gamma)
+}
+
+/// Whether an inlet with a given throat to capture area ratio self-starts
+/// at a freestream Mach number
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{self_starts, Inlet};
+///
+/// assert!(self_starts(2.0_f64, 1.4, 0.9));
+/// assert!(!self_starts(2.0_f64, 1.4, 0.7));
+/// assert_eq!(Inlet::new(1.3_f64, 1.8).self_starts(2.0, 1.4), false);
+/// ```
+pub fn self_starts<F: Float>(mach: F, gamma: F, throat_capture_ratio: F) -> bool {
+    throat_capture_ratio * kantrowitz_limit(mach, gamma) >= F::one()
+}
+
+/// Unstart margins over a grid of freestream Mach numbers and back pressure
+/// ratios
+///
+/// `margins[i][j]` is [`Inlet::unstart_margin`] at `machs[i]` and
+/// `back_pressures[j]`.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{unstart_margin_map, Inlet};
+///
+/// let inlet = Inlet::new(1.3_f64, 1.8);
+/// let margins = unstart_margin_map(&inlet, &[1.5, 2.0, 2.5], &[5.0, 7.0], 1.4);
+/// assert!(margins[1][0].unwrap() > 0.0);
+/// assert!(margins[1][1].unwrap() < 0.0);
+/// assert_eq!(margins[0][0], None);
+/// ```
+pub fn unstart_margin_map<F: Float>(
+    inlet: &Inlet<F>,
+    machs: &[F],
+    back_pressures: &[F],
+    gamma: F,
+) -> Vec<Vec<Option<F>>> {
+    machs
+        .iter()
+        .map(|&mach| {
+            back_pressures
+                .iter()
+                .map(|&pb_p| inlet.unstart_margin(mach, gamma, pb_p))
+                .collect()
+        })
+        .collect()
+}
+
+/// Model of inlet stagnation pressure recovery against freestream Mach number
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::InletRecovery;
+///
+/// assert_eq!(InletRecovery::MilE5008B.recovery(0.8_f64, 1.4), 1.0);
+/// assert_eq!(InletRecovery::MilE5008B.recovery(2.0_f64, 1.4), 0.925);
+/// assert_eq!(InletRecovery::MilE5008B.recovery(6.0_f64, 1.4), 0.35858359480053786);
+/// assert_eq!(InletRecovery::NormalShock.recovery(2.0_f64, 1.4), 0.7208738614847452);
+/// assert_eq!(InletRecovery::Oswatitsch { shocks: 2 }.recovery(2.0_f64, 1.4), 0.9560825061721356);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InletRecovery {
+    /// MIL-E-5008B specification recovery, 1 - 0.075 (M - 1)^1.35 up to
+    /// Mach 5 and 800 / (M^4 + 935) above it. The usual reference curve for
+    /// engine cycle analysis.
+    MilE5008B,
+    /// A single normal shock, as in a pitot inlet.
+    NormalShock,
+    /// Oblique shocks designed by the Oswatitsch criterion followed by a
+    /// terminal normal shock, see [`oswatitsch_inlet`].
+    Oswatitsch {
+        /// Number of oblique shocks.
+        shocks: usize,
+    },
+}
+
+impl InletRecovery {
+    /// Stagnation pressure recovery p02/p01 at a freestream Mach number,
+    /// one for subsonic flight.
+    pub fn recovery<F: Float>(&self, mach: F, gamma: F) -> F {
+        validate::mach(mach);
+        validate::gamma(gamma);
+        if mach <= F::one() {
+            return F::one();
+        }
+        match *self {
+            Self::MilE5008B => {
+                let five = F::from(5.).unwrap();
+                if mach <= five {
+                    F::one()
+                        - F::from(0.075).unwrap() * (mach - F::one()).powf(F::from(1.35).unwrap())
+                } else {
+                    F::from(800.).unwrap() / (mach.powi(4) + F::from(935.).unwrap())
+                }
+            }
+            Self::NormalShock => normal_p02_p01(mach, gamma),
+            Self::Oswatitsch { shocks } => oswatitsch_inlet(mach, gamma, shocks).recovery,
+        }
+    }
+}
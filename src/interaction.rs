@@ -0,0 +1,167 @@
+//! Shock-shock interactions on a bow shock
+//!
+//! A planar shock from a wedge below the body, turning the free stream
+//! upwards, impinges on the bow shock of a blunt body. Edney (1968) sorted the
+//! resulting patterns into six types by where along the bow shock the
+//! impingement happens:
+//!
+//! - Types I and II on the lower bow shock below its sonic point, where the
+//!   two shocks are of opposite families. Type I is a regular crossing and
+//!   Type II, which forms when the polars admit no regular crossing, a Mach
+//!   stem between two triple points.
+//! - Types III and IV on the subsonic part of the lower bow shock, where the
+//!   shear layer either attaches to the body or a supersonic jet forms.
+//! - Types V and VI on the upper bow shock, where the shocks are of the same
+//!   family, inside and beyond the upper sonic point.
+//!
+//! The local bow shock is described by its wave angle at the impingement
+//! point, so the geometry of the body never enters.
+use crate::{
+    oblique_beta_max, polar_intersections, validate, ObliqueShock, PolarIntersection,
+    ShockDetached, ShockPolar,
+};
+use num::Float;
+
+/// Edney classification of a shock-shock interaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdneyType {
+    /// Regular crossing of opposite-family shocks.
+    I,
+    /// Mach stem between opposite-family shocks.
+    II,
+    /// Shear layer attaching to the body.
+    III,
+    /// Supersonic jet striking the body.
+    IV,
+    /// Same-family interaction inside the upper sonic point.
+    V,
+    /// Same-family interaction beyond the upper sonic point, ending in an
+    /// expansion.
+    VI,
+}
+
+/// Classified shock-shock interaction
+///
+/// Returned by [`edney_interaction`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdneyInteraction<F> {
+    /// Interaction type.
+    pub edney_type: EdneyType,
+    /// Flow direction and pressure, relative to the free stream, either side
+    /// of the slip line leaving the interaction. For Type I this is the
+    /// crossing of the polars behind the impinging and bow shocks; for
+    /// Type II the crossing of the polar behind the impinging shock with the
+    /// strong, downward-turning branch of the free-stream polar, at the upper
+    /// triple point.
+    /// `None` for the other types, which depend on the body shape.
+    pub slip: Option<PolarIntersection<F>>,
+}
+
+/// Edney type and slip line state for an impinging shock meeting a bow shock
+///
+/// The impinging shock turns the free stream upwards through
+/// `theta_impinging` radians. The bow shock is described by its local wave
+/// angle `bow_beta` relative to the free stream, negative on the lower half,
+/// which turns the flow downwards, and positive on the upper half. Its
+/// magnitude must lie between the Mach angle and pi/2.
+///
+/// Returns [`ShockDetached`] when the impinging shock cannot stay attached
+/// or leaves subsonic flow behind it.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{edney_interaction, EdneyType};
+///
+/// let (mach, gamma, theta) = (4.0_f64, 1.4, 10_f64.to_radians());
+///
+/// // Far down the lower bow shock the shocks cross regularly.
+/// let far = edney_interaction(mach, gamma, theta, -20_f64.to_radians())?;
+/// assert_eq!(far.edney_type, EdneyType::I);
+/// let slip = far.slip.unwrap();
+/// assert_eq!((slip.theta, slip.p), (0.04405491099407827, 4.535755167971621));
+///
+/// let stem = edney_interaction(mach, gamma, theta, -55_f64.to_radians())?;
+/// assert_eq!(stem.edney_type, EdneyType::II);
+/// assert!(stem.slip.unwrap().strong_b);
+///
+/// let upper = edney_interaction(mach, gamma, theta, 30_f64.to_radians())?;
+/// assert_eq!(upper.edney_type, EdneyType::VI);
+/// # Ok::<(), comp_flow::ShockDetached>(())
+/// ```
+pub fn edney_interaction<F: Float>(
+    mach: F,
+    gamma: F,
+    theta_impinging: F,
+    bow_beta: F,
+) -> Result<EdneyInteraction<F>, ShockDetached> {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let impinging = ObliqueShock::new(mach, gamma, theta_impinging)?;
+    if impinging.mach2() <= F::one() {
+        return Err(ShockDetached);
+    }
+    let sonic = beta_sonic(mach, gamma);
+    let beta = bow_beta.abs();
+    let classified = |edney_type| {
+        Ok(EdneyInteraction {
+            edney_type,
+            slip: None,
+        })
+    };
+
+    if bow_beta > F::zero() {
+        return classified(if beta < sonic {
+            EdneyType::VI
+        } else {
+            EdneyType::V
+        });
+    }
+
+    let free = ShockPolar::new(mach, gamma);
+    let behind_impinging =
+        ShockPolar::from_state(impinging.mach2(), gamma, impinging.p2_p1(), theta_impinging);
+    // The flow behind the impinging shock meets the strong, lower part of
+    // the bow shock through a weak shock turning it downwards.
+    let stem = polar_intersections(&behind_impinging, &free)
+        .into_iter()
+        .find(|x| !x.strong_a && x.strong_b && x.theta < F::zero());
+    if beta >= sonic {
+        // A jet forms when the flow behind that weak shock is still
+        // supersonic.
+        let jet = stem
+            .and_then(|x| behind_impinging.deflect(x.theta).ok())
+            .is_some_and(|s| s.mach() > F::one());
+        return classified(if jet { EdneyType::IV } else { EdneyType::III });
+    }
+
+    let bow = ObliqueShock::from_beta(mach, gamma, beta);
+    let behind_bow = ShockPolar::from_state(bow.mach2(), gamma, bow.p2_p1(), -bow.theta());
+    let regular = polar_intersections(&behind_impinging, &behind_bow)
+        .into_iter()
+        .find(|x| !x.strong_a && !x.strong_b);
+    if let Some(slip) = regular {
+        return Ok(EdneyInteraction {
+            edney_type: EdneyType::I,
+            slip: Some(slip),
+        });
+    }
+    Ok(EdneyInteraction {
+        edney_type: EdneyType::II,
+        slip: stem,
+    })
+}
+
+/// Wave angle at which the flow behind an oblique shock is exactly sonic.
+fn beta_sonic<F: Float>(mach: F, gamma: F) -> F {
+    let m2 = mach.powi(2);
+    let three = F::from(3.).unwrap();
+    let gp1 = gamma + F::one();
+    let root = (gp1
+        * (gp1 * m2.powi(2) - F::from(2.).unwrap() * (three - gamma) * m2
+            + gamma
+            + F::from(9.).unwrap()))
+    .sqrt();
+    let sin2 = (gp1 * m2 - (three - gamma) + root) / (F::from(4.).unwrap() * gamma * m2);
+    sin2.sqrt().min(oblique_beta_max(mach, gamma).sin()).asin()
+}
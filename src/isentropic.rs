@@ -0,0 +1,357 @@
+//! Isentropic flow state for a single Mach number.
+use crate::{
+    mach_from_a_ac, mach_from_p_p0, mach_from_pm_angle, mach_from_rho_rho0, mach_from_t_t0,
+    validate, Gas, Quantity,
+};
+use num::Float;
+use std::cmp::Ordering;
+use std::{error::Error, fmt};
+
+/// All isentropic ratios for one Mach number and specific heat ratio
+///
+/// The ratios share the term `1 + (gamma - 1) / 2 * mach^2`, which is computed
+/// once on construction. This makes `Isentropic` cheaper than calling the
+/// individual `mach_to_*` functions when tabulating several ratios.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{mach_to_a_ac, Isentropic};
+///
+/// let state = Isentropic::new(2.0_f64, 1.4);
+/// assert_eq!(state.t_t0(), 0.5555555555555556);
+/// assert_eq!(state.p_p0(), 0.12780452546295096);
+/// assert_eq!(state.a_ac(), mach_to_a_ac(2.0, 1.4));
+/// assert_eq!(state.mach_angle(), 0.5235987755982989);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Isentropic<F> {
+    mach: F,
+    gamma: F,
+    stag: F,
+}
+
+impl<F: Float> Isentropic<F> {
+    /// Isentropic state for a given Mach number and specific heat ratio.
+    pub fn new(mach: F, gamma: F) -> Self {
+        validate::mach(mach);
+        validate::gamma(gamma);
+        let half = F::from(0.5).unwrap();
+        Self {
+            mach,
+            gamma,
+            stag: F::one() + half * (gamma - F::one()) * mach.powi(2),
+        }
+    }
+
+    /// Isentropic state for a gas at static temperature `t`
+    ///
+    /// The specific heat ratio is taken at `t` and held constant, which is
+    /// exact for a calorically perfect gas and a local approximation for a
+    /// thermally perfect one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::{gases, HarmonicOscillator, Isentropic};
+    ///
+    /// let air = gases::air();
+    /// assert_eq!(Isentropic::from_gas(&air, 2.0_f64, 300.0), Isentropic::new(2.0, 1.4));
+    ///
+    /// // Vibration lowers gamma in hot nitrogen.
+    /// let n2 = HarmonicOscillator::new(1.4_f64, 296.8, 3353.0);
+    /// assert!(Isentropic::from_gas(&n2, 2.0, 2000.0).gamma() < 1.4);
+    /// ```
+    pub fn from_gas<G: Gas<F>>(gas: &G, mach: F, t: F) -> Self {
+        Self::new(mach, gas.gamma(t))
+    }
+
+    /// Mach number.
+    pub fn mach(&self) -> F {
+        self.mach
+    }
+
+    /// Specific heat ratio.
+    pub fn gamma(&self) -> F {
+        self.gamma
+    }
+
+    /// Total temperature ratio.
+    pub fn t_t0(&self) -> F {
+        self.stag.recip()
+    }
+
+    /// Total pressure ratio.
+    pub fn p_p0(&self) -> F {
+        self.stag.powf(self.gamma / (F::one() - self.gamma))
+    }
+
+    /// Stagnation density ratio.
+    pub fn rho_rho0(&self) -> F {
+        self.stag.powf(F::one() / (F::one() - self.gamma))
+    }
+
+    /// Critical area ratio.
+    pub fn a_ac(&self) -> F {
+        let half = F::from(0.5).unwrap();
+        let gamma = self.gamma;
+        F::one() / self.mach
+            * (self.stag / (half * (gamma + F::one())))
+                .powf(half * (gamma + F::one()) / (gamma - F::one()))
+    }
+
+    /// Normalised velocity V/sqrt(cp T0).
+    pub fn v_cpt0(&self) -> F {
+        self.mach * ((self.gamma - F::one()) / self.stag).sqrt()
+    }
+
+    /// Mass flow function m sqrt(cp T0) / (A p0).
+    pub fn mcpt0_ap0(&self) -> F {
+        let half = F::from(0.5).unwrap();
+        let gamma = self.gamma;
+        gamma / (gamma - F::one()).sqrt()
+            * self.mach
+            * self
+                .stag
+                .powf(-half * (gamma + F::one()) / (gamma - F::one()))
+    }
+
+    /// Mass flow function m sqrt(cp T0) / (A p).
+    pub fn mcpt0_ap(&self) -> F {
+        self.gamma / (self.gamma - F::one()).sqrt() * self.mach * self.stag.sqrt()
+    }
+
+    /// Prandtl-Meyer angle in radians, NaN for subsonic flow.
+    pub fn pm_angle(&self) -> F {
+        let gamma = self.gamma;
+        ((gamma + F::one()) / (gamma - F::one())).sqrt()
+            * ((gamma - F::one()) / (gamma + F::one()) * (self.mach.powi(2) - F::one()))
+                .sqrt()
+                .atan()
+            - (self.mach.powi(2) - F::one()).sqrt().atan()
+    }
+
+    /// Mach angle in radians, NaN for subsonic flow.
+    pub fn mach_angle(&self) -> F {
+        self.mach.recip().asin()
+    }
+
+    /// Every ratio of the state, labelled with its registered quantity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::{Isentropic, Quantity};
+    ///
+    /// let state = Isentropic::new(2.0_f64, 1.4);
+    /// let (quantity, value) = state.quantities()[2];
+    /// assert_eq!(quantity, &Quantity::T_T0);
+    /// assert_eq!(value, state.t_t0());
+    /// ```
+    pub fn quantities(&self) -> Vec<(&'static Quantity, F)> {
+        vec![
+            (&Quantity::MACH, self.mach),
+            (&Quantity::GAMMA, self.gamma),
+            (&Quantity::T_T0, self.t_t0()),
+            (&Quantity::P_P0, self.p_p0()),
+            (&Quantity::RHO_RHO0, self.rho_rho0()),
+            (&Quantity::A_AC, self.a_ac()),
+            (&Quantity::V_CPT0, self.v_cpt0()),
+            (&Quantity::MCPT0_AP0, self.mcpt0_ap0()),
+            (&Quantity::MCPT0_AP, self.mcpt0_ap()),
+            (&Quantity::PM_ANGLE, self.pm_angle()),
+            (&Quantity::MACH_ANGLE, self.mach_angle()),
+        ]
+    }
+}
+
+/// A known isentropic quantity, used to look up the full state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IsentropicQuantity<F> {
+    /// Mach number.
+    Mach(F),
+    /// Total temperature ratio T/T0.
+    TT0(F),
+    /// Total pressure ratio p/p0.
+    PP0(F),
+    /// Total density ratio rho/rho0.
+    RhoRho0(F),
+    /// Critical area ratio A/A*, which has a subsonic and a supersonic
+    /// solution.
+    AAc(F),
+    /// Prandtl-Meyer angle in radians.
+    PmAngle(F),
+}
+
+impl<F: Float> IsentropicQuantity<F> {
+    /// Registry entry describing this quantity.
+    pub fn quantity(&self) -> &'static Quantity {
+        match self {
+            Self::Mach(_) => &Quantity::MACH,
+            Self::TT0(_) => &Quantity::T_T0,
+            Self::PP0(_) => &Quantity::P_P0,
+            Self::RhoRho0(_) => &Quantity::RHO_RHO0,
+            Self::AAc(_) => &Quantity::A_AC,
+            Self::PmAngle(_) => &Quantity::PM_ANGLE,
+        }
+    }
+
+    /// Quantity with a registered name or symbol and a given value.
+    ///
+    /// Returns `None` for names that cannot identify an isentropic state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::IsentropicQuantity;
+    ///
+    /// assert_eq!(
+    ///     IsentropicQuantity::from_name("p/p0", 0.5_f64),
+    ///     Some(IsentropicQuantity::PP0(0.5))
+    /// );
+    /// assert_eq!(IsentropicQuantity::from_name("beta", 0.5_f64), None);
+    /// ```
+    pub fn from_name(name: &str, value: F) -> Option<Self> {
+        let quantity = Quantity::by_name(name)?;
+        [
+            Self::Mach(value),
+            Self::TT0(value),
+            Self::PP0(value),
+            Self::RhoRho0(value),
+            Self::AAc(value),
+            Self::PmAngle(value),
+        ]
+        .into_iter()
+        .find(|q| q.quantity() == quantity)
+    }
+
+    /// Mach numbers consistent with this quantity.
+    fn machs(&self, gamma: F) -> Vec<F> {
+        match *self {
+            Self::Mach(m) => vec![m],
+            Self::TT0(r) => vec![mach_from_t_t0(r, gamma)],
+            Self::PP0(r) => vec![mach_from_p_p0(r, gamma)],
+            Self::RhoRho0(r) => vec![mach_from_rho_rho0(r, gamma)],
+            Self::AAc(r) => vec![
+                mach_from_a_ac(r, gamma, false),
+                mach_from_a_ac(r, gamma, true),
+            ],
+            Self::PmAngle(nu) => vec![mach_from_pm_angle(nu, gamma)],
+        }
+    }
+}
+
+/// Reason an isentropic state could not be found from two quantities
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IsentropicLookupError<F> {
+    /// The quantities imply Mach numbers differing by more than the tolerance.
+    Inconsistent {
+        /// Mach number implied by the first quantity.
+        mach_a: F,
+        /// Mach number implied by the second quantity.
+        mach_b: F,
+    },
+    /// Both quantities are area ratios, which cannot select a branch.
+    Ambiguous,
+    /// A quantity lies outside the range reached by any isentropic state,
+    /// such as a temperature ratio above one.
+    OutOfDomain,
+}
+
+impl<F: Float> fmt::Display for IsentropicLookupError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Inconsistent { mach_a, mach_b } => write!(
+                f,
+                "quantities imply different Mach numbers, {} and {}",
+                mach_a.to_f64().unwrap_or(f64::NAN),
+                mach_b.to_f64().unwrap_or(f64::NAN)
+            ),
+            Self::Ambiguous => write!(f, "two area ratios cannot select a flow branch"),
+            Self::OutOfDomain => write!(f, "quantity is outside the isentropic range"),
+        }
+    }
+}
+
+impl<F: Float + fmt::Debug> Error for IsentropicLookupError<F> {}
+
+/// Isentropic state from any two known quantities
+///
+/// Each quantity is converted to the Mach number it implies and the two must
+/// agree to within a relative `tolerance`. An area ratio paired with any
+/// other quantity takes the branch the other quantity selects, so a
+/// measured pressure ratio can resolve whether a nozzle station is subsonic
+/// or supersonic.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{isentropic_lookup, IsentropicLookupError, IsentropicQuantity};
+///
+/// let state = isentropic_lookup(
+///     IsentropicQuantity::AAc(1.6875_f64),
+///     IsentropicQuantity::PP0(0.13),
+///     1.4,
+///     0.05,
+/// )
+/// .unwrap();
+/// assert_eq!(state.mach(), 1.989047144557267);
+///
+/// let inconsistent = isentropic_lookup(
+///     IsentropicQuantity::Mach(2.0_f64),
+///     IsentropicQuantity::TT0(0.9),
+///     1.4,
+///     0.05,
+/// );
+/// assert!(inconsistent.is_err());
+///
+/// // The strict feature rejects a temperature ratio above one outright.
+/// # #[cfg(not(feature = "strict"))]
+/// # {
+/// let out_of_domain = isentropic_lookup(
+///     IsentropicQuantity::TT0(1.5_f64),
+///     IsentropicQuantity::Mach(2.0),
+///     1.4,
+///     0.05,
+/// );
+/// assert_eq!(out_of_domain, Err(IsentropicLookupError::OutOfDomain));
+/// # }
+/// ```
+pub fn isentropic_lookup<F: Float>(
+    a: IsentropicQuantity<F>,
+    b: IsentropicQuantity<F>,
+    gamma: F,
+    tolerance: F,
+) -> Result<Isentropic<F>, IsentropicLookupError<F>> {
+    validate::gamma(gamma);
+    if matches!(
+        (a, b),
+        (IsentropicQuantity::AAc(_), IsentropicQuantity::AAc(_))
+    ) {
+        return Err(IsentropicLookupError::Ambiguous);
+    }
+    let (machs_a, machs_b) = (a.machs(gamma), b.machs(gamma));
+    let (mach_a, mach_b) = machs_a
+        .iter()
+        .flat_map(|&ma| machs_b.iter().map(move |&mb| (ma, mb)))
+        .min_by(|x, y| {
+            let dx = (x.0 - x.1).abs();
+            let dy = (y.0 - y.1).abs();
+            dx.partial_cmp(&dy).unwrap_or(Ordering::Equal)
+        })
+        .unwrap();
+    if !mach_a.is_finite() || !mach_b.is_finite() {
+        return Err(IsentropicLookupError::OutOfDomain);
+    }
+    if (mach_a - mach_b).abs() > tolerance * mach_a.max(mach_b) {
+        return Err(IsentropicLookupError::Inconsistent { mach_a, mach_b });
+    }
+    // Prefer the quantity with a unique solution, which is the better
+    // conditioned of the two.
+    let mach = if matches!(a, IsentropicQuantity::AAc(_)) {
+        mach_b
+    } else {
+        mach_a
+    };
+    Ok(Isentropic::new(mach, gamma))
+}
@@ -4,19 +4,125 @@
 //!
 //! <div class="warning">
 //!
-//! The included functions have no input checking or error handling whatsoever.
-//! Invalid (non-physical) inputs such as mach < 1 for a shock relation or gamma < 1
-//! may produce non-sensical outputs.
+//! By default, inputs are not checked for physical validity. Invalid
+//! (non-physical) inputs such as mach < 1 for a shock relation or gamma < 1 may
+//! produce non-sensical outputs or NaN rather than an error. Functions with a
+//! failure callers are expected to handle, such as a detached oblique shock or
+//! inconsistent measurements, return a `Result` instead. Enable the `strict`
+//! feature to catch invalid inputs while developing.
 //!
 //! </div>
 //!
+//! Without the `strict` feature every function is total over finite inputs:
+//! none panics and every iteration is bounded. Inputs outside a relation's
+//! domain, and iterative solves that fail to converge, give NaN, or an `Err`
+//! for functions that return a `Result`.
+//!
+//! ## Features
+//!
+//! - `strict`: Enables debug assertions that check the dimensionless inputs
+//!   for physical validity (gamma > 1, mach >= 1 for shock relations,
+//!   stagnation ratios in (0, 1], angles within their domain) and panic with a
+//!   descriptive message. Dimensional inputs such as pressures, temperatures
+//!   and lengths are not checked.
+//!   The checks are compiled out in release builds and when the feature is off.
+//!
 #![warn(missing_docs)]
 
+pub mod accuracy;
+pub mod acoustics;
+pub mod airspeed;
+pub mod atmosphere;
+pub mod ballistic;
+pub mod boundary;
+pub mod calibration;
+pub mod cold_gas;
+pub mod compressibility;
+pub mod decelerator;
+pub mod dimensional;
+pub mod duct;
+pub mod envelope;
+pub mod expansion;
+pub mod field;
+pub mod flow_path;
+pub mod gas;
+pub mod gases;
+pub mod heating;
+pub mod hugoniot;
+pub mod hypersonic;
+pub mod incompressible;
+pub mod inlet;
+pub mod interaction;
+pub mod isentropic;
 pub mod mach_from;
 pub mod mach_to;
 pub mod normal;
+pub mod nozzle;
 pub mod oblique;
+pub mod polar;
+pub mod quantity;
+pub mod ramp;
+pub mod riemann;
+pub mod scales;
+pub mod shock_tube;
+pub mod sonic;
+pub mod thermally_perfect;
+pub mod trajectory;
+pub mod transport;
+pub mod traverse;
+mod validate;
+pub mod venturi;
+pub mod wave;
+pub mod wind_tunnel;
 
+#[doc(inline)]
+pub use accuracy::*;
+#[doc(inline)]
+pub use acoustics::*;
+#[doc(inline)]
+pub use airspeed::*;
+#[doc(inline)]
+pub use atmosphere::*;
+#[doc(inline)]
+pub use ballistic::*;
+#[doc(inline)]
+pub use boundary::*;
+#[doc(inline)]
+pub use calibration::*;
+#[doc(inline)]
+pub use cold_gas::*;
+#[doc(inline)]
+pub use compressibility::*;
+#[doc(inline)]
+pub use decelerator::*;
+#[doc(inline)]
+pub use dimensional::*;
+#[doc(inline)]
+pub use duct::*;
+#[doc(inline)]
+pub use envelope::*;
+#[doc(inline)]
+pub use expansion::*;
+#[doc(inline)]
+pub use field::*;
+#[doc(inline)]
+pub use flow_path::*;
+#[doc(inline)]
+pub use gas::*;
+#[doc(inline)]
+pub use heating::*;
+#[doc(inline)]
+pub use hugoniot::*;
+#[doc(inline)]
+pub use hypersonic::*;
+#[doc(inline)]
+pub use incompressible::*;
+#[doc(inline)]
+pub use inlet::*;
+#[doc(inline)]
+pub use interaction::*;
+#[doc(inline)]
+pub use isentropic::*;
 #[doc(inline)]
 pub use mach_from::*;
 #[doc(inline)]
@@ -24,4 +130,34 @@ pub use mach_to::*;
 #[doc(inline)]
 pub use normal::*;
 #[doc(inline)]
+pub use nozzle::*;
+#[doc(inline)]
 pub use oblique::*;
+#[doc(inline)]
+pub use polar::*;
+#[doc(inline)]
+pub use quantity::*;
+#[doc(inline)]
+pub use ramp::*;
+#[doc(inline)]
+pub use riemann::*;
+#[doc(inline)]
+pub use scales::*;
+#[doc(inline)]
+pub use shock_tube::*;
+#[doc(inline)]
+pub use sonic::*;
+#[doc(inline)]
+pub use thermally_perfect::*;
+#[doc(inline)]
+pub use trajectory::*;
+#[doc(inline)]
+pub use transport::*;
+#[doc(inline)]
+pub use traverse::*;
+#[doc(inline)]
+pub use venturi::*;
+#[doc(inline)]
+pub use wave::*;
+#[doc(inline)]
+pub use wind_tunnel::*;
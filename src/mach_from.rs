@@ -1,6 +1,6 @@
 //! Collection of functions for isentropic compressible flow.
 
-use crate::{mach_to_a_ac, mach_to_pm_angle};
+use crate::{mach_to, mach_to_p_p0, validate};
 use eqsolver::single_variable::FDNewton;
 use num::Float;
 
@@ -8,7 +8,7 @@ use num::Float;
 ///
 /// <div class="warning">
 ///
-/// This function uses Newton's method to solve for the Mach number. If this 
+/// This function uses Newton's method to solve for the Mach number. If this
 /// function must be called many times, it may be preferable to make a look up
 /// table with `mach_to_pm_angle` and interpolate those values.
 ///
@@ -23,9 +23,11 @@ use num::Float;
 /// assert_eq!(mach_from_pm_angle(0.0_f64, 1.4_f64),  1.00000022981460310);
 /// ```
 pub fn mach_from_pm_angle<F: Float>(pm_angle: F, gamma: F) -> F {
-    let f = |m| mach_to_pm_angle(m, gamma) - pm_angle;
+    validate::pm_angle(pm_angle, gamma);
+    validate::gamma(gamma);
+    let f = |m| mach_to::pm_angle(m, gamma) - pm_angle;
     let x0 = F::from(2.).unwrap();
-    FDNewton::new(f).solve(x0).unwrap()
+    FDNewton::new(f).solve(x0).unwrap_or(F::nan())
 }
 
 /// Mach number for a given mach angle in radians.
@@ -39,6 +41,7 @@ pub fn mach_from_pm_angle<F: Float>(pm_angle: F, gamma: F) -> F {
 /// assert_eq!(mach_from_mach_angle(1.5707963267948966_f64), 1.0);
 /// ```
 pub fn mach_from_mach_angle<F: Float>(mach_angle: F) -> F {
+    validate::mach_angle(mach_angle);
     // TODO check for invalid input i.e. mach_angle > 90 deg
     (F::one()) / mach_angle.sin()
 }
@@ -55,6 +58,8 @@ pub fn mach_from_mach_angle<F: Float>(mach_angle: F) -> F {
 /// assert_eq!(mach_from_t_t0(0.55555556_f32, 1.4), 2.0);
 /// ```
 pub fn mach_from_t_t0<F: Float>(t_t0: F, gamma: F) -> F {
+    validate::ratio("t_t0", t_t0);
+    validate::gamma(gamma);
     let two = F::from(2.0).unwrap();
     (two / (gamma - F::one()) * (F::one() / t_t0 - F::one())).sqrt()
 }
@@ -71,6 +76,8 @@ pub fn mach_from_t_t0<F: Float>(t_t0: F, gamma: F) -> F {
 /// assert_eq!(mach_from_p_p0(0.1278045254629509, 1.4), 2.0);
 /// ```
 pub fn mach_from_p_p0<F: Float>(p_p0: F, gamma: F) -> F {
+    validate::ratio("p_p0", p_p0);
+    validate::gamma(gamma);
     let two = F::from(2.0).unwrap();
     (two / (gamma - F::one()) * (p_p0.powf((F::one() - gamma) / gamma) - F::one())).sqrt()
 }
@@ -87,6 +94,8 @@ pub fn mach_from_p_p0<F: Float>(p_p0: F, gamma: F) -> F {
 /// assert_eq!(mach_from_rho_rho0(0.2300481458333117, 1.4), 2.0);
 /// ```
 pub fn mach_from_rho_rho0<F: Float>(rho_rho0: F, gamma: F) -> F {
+    validate::ratio("rho_rho0", rho_rho0);
+    validate::gamma(gamma);
     let two = F::from(2.0).unwrap();
     (two / (gamma - F::one()) * (rho_rho0.powf(F::one() - gamma) - F::one())).sqrt()
 }
@@ -95,7 +104,7 @@ pub fn mach_from_rho_rho0<F: Float>(rho_rho0: F, gamma: F) -> F {
 ///
 /// <div class="warning">
 ///
-/// This function uses Newton's method to solve for the Mach number. If this 
+/// This function uses Newton's method to solve for the Mach number. If this
 /// function must be called many times, it may be preferable to make a look up
 /// table with `mach_to_a_ac` and interpolate those values.
 ///
@@ -106,21 +115,112 @@ pub fn mach_from_rho_rho0<F: Float>(rho_rho0: F, gamma: F) -> F {
 /// ```
 /// use comp_flow::mach_from_a_ac;
 ///
-/// assert_eq!(mach_from_a_ac(5.821828750000001, 1.4, false), 0.1);
+/// assert_eq!(mach_from_a_ac(5.821828750000001, 1.4, false), 0.09999999999999998);
 /// assert_eq!(mach_from_a_ac(1.0, 1.4, false), 1.0);
+/// assert_eq!(mach_from_a_ac(2.0, 1.4, false), 0.3059038341891082);
 /// assert_eq!(mach_from_a_ac(1.0, 1.4, true), 1.0);
 /// assert_eq!(mach_from_a_ac(1.6875000000000002, 1.4, true), 2.0);
 /// ```
 pub fn mach_from_a_ac<F: Float>(a_ac: F, gamma: F, supersonic: bool) -> F {
+    validate::area_ratio(a_ac);
+    validate::gamma(gamma);
     if a_ac.is_one() {
         return F::one();
     }
-    let f = |m| mach_to_a_ac(m, gamma) - a_ac;
-    let x0: F;
-    if supersonic {
-        x0 = F::from(1.01).unwrap();
+    let f = |m| mach_to::a_ac(m, gamma) - a_ac;
+    // Starting below the subsonic root keeps Newton from jumping to the
+    // supersonic branch, since A/A* is convex and decreasing there.
+    let half = F::from(0.5).unwrap();
+    let x0 = if supersonic {
+        F::from(1.01).unwrap()
     } else {
-        x0 = F::from(0.99).unwrap();
+        (half * (gamma + F::one())).powf(-half * (gamma + F::one()) / (gamma - F::one())) / a_ac
+    };
+    FDNewton::new(f).solve(x0).unwrap_or(F::nan())
+}
+
+/// Mach number for a given supersonic pitot to static pressure ratio p02/p1
+///
+/// Inverts the Rayleigh pitot formula, see `mach_to_pitot_p02_p1`.
+///
+/// <div class="warning">
+///
+/// This function uses Newton's method to solve for the Mach number.
+///
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_from_pitot_p02_p1;
+///
+/// assert_eq!(mach_from_pitot_p02_p1(5.640440812823317, 1.4), 2.0);
+/// assert_eq!(mach_from_pitot_p02_p1(1.892929158737854_f64, 1.4), 1.0000000000000009);
+///
+/// // Ratios below one have no solution.
+/// assert!(mach_from_pitot_p02_p1(0.5_f64, 1.4).is_nan());
+/// ```
+pub fn mach_from_pitot_p02_p1<F: Float>(p02_p1: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    let f = |m| mach_to::pitot_p02_p1(m, gamma) - p02_p1;
+    let x0 = F::from(2.).unwrap();
+    FDNewton::new(f).solve(x0).unwrap_or(F::nan())
+}
+
+/// Mach number for a given ratio of measured pitot pressure to static
+/// pressure, in either regime
+///
+/// Ratios below the sonic value are reduced with the isentropic relation and
+/// those above it with the Rayleigh pitot formula.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_from_pitot;
+///
+/// assert_eq!(mach_from_pitot(1.1862126380443982, 1.4), 0.5000000000000002);
+/// assert_eq!(mach_from_pitot(5.640440812823317, 1.4), 2.0);
+/// ```
+pub fn mach_from_pitot<F: Float>(p_pitot_p: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    let sonic = F::one() / mach_to_p_p0(F::one(), gamma);
+    if p_pitot_p <= sonic {
+        mach_from_p_p0(F::one() / p_pitot_p, gamma)
+    } else {
+        mach_from_pitot_p02_p1(p_pitot_p, gamma)
     }
-    FDNewton::new(f).solve(x0).unwrap()
+}
+
+/// Mach number for a given characteristic Mach number M* = V/a*
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_from_mach_star;
+///
+/// assert_eq!(mach_from_mach_star(1.0, 1.4), 1.0);
+/// assert_eq!(mach_from_mach_star(1.632993161855452, 1.4), 1.9999999999999998);
+/// ```
+pub fn mach_from_mach_star<F: Float>(mach_star: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    let two = F::from(2.).unwrap();
+    (two * mach_star.powi(2) / ((gamma + F::one()) - (gamma - F::one()) * mach_star.powi(2))).sqrt()
+}
+
+/// Mach number for a given Crocco number C = V/V_max
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_from_crocco;
+///
+/// assert_eq!(mach_from_crocco(0.0, 1.4), 0.0);
+/// assert_eq!(mach_from_crocco(0.40824829046386296, 1.4), 1.0);
+/// assert_eq!(mach_from_crocco(0.6666666666666666, 1.4), 2.0);
+/// ```
+pub fn mach_from_crocco<F: Float>(crocco: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    let two = F::from(2.).unwrap();
+    let c2 = crocco.powi(2);
+    (two * c2 / ((gamma - F::one()) * (F::one() - c2))).sqrt()
 }
@@ -1,4 +1,5 @@
 //! Collection of functions for isentropic compressible flow.
+use crate::validate;
 #[doc(no_inline)]
 use num::Float;
 
@@ -13,6 +14,13 @@ use num::Float;
 /// assert_eq!(mach_to_pm_angle(1.0_f64, 1.4_f64), 0.0);
 /// ```
 pub fn mach_to_pm_angle<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    pm_angle(mach, gamma)
+}
+
+/// Unchecked Prandtl-Meyer angle, safe to evaluate at solver iterates.
+pub(crate) fn pm_angle<F: Float>(mach: F, gamma: F) -> F {
     ((gamma + F::one()) / (gamma - F::one())).sqrt()
         * ((gamma - F::one()) / (gamma + F::one()) * (mach.powi(2) - F::one()))
             .sqrt()
@@ -31,6 +39,7 @@ pub fn mach_to_pm_angle<F: Float>(mach: F, gamma: F) -> F {
 /// assert_eq!(mach_to_mach_angle(1.0_f64), 1.5707963267948966);
 /// ```
 pub fn mach_to_mach_angle<F: Float>(mach: F) -> F {
+    validate::supersonic(mach);
     (F::one() / mach).asin()
 }
 
@@ -46,6 +55,8 @@ pub fn mach_to_mach_angle<F: Float>(mach: F) -> F {
 /// assert_eq!(mach_to_t_t0(2.0_f32, 1.4), 0.55555556);
 /// ```
 pub fn mach_to_t_t0<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
     let half = F::from(0.5).unwrap();
     (F::one() + half * (gamma - F::one()) * mach.powi(2)).powi(-1)
 }
@@ -62,6 +73,13 @@ pub fn mach_to_t_t0<F: Float>(mach: F, gamma: F) -> F {
 /// assert_eq!(mach_to_p_p0(2.0, 1.4), 0.12780452546295096);
 /// ```
 pub fn mach_to_p_p0<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    p_p0(mach, gamma)
+}
+
+/// Unchecked total pressure ratio, safe to evaluate at solver iterates.
+pub(crate) fn p_p0<F: Float>(mach: F, gamma: F) -> F {
     let half = F::from(0.5).unwrap();
     (F::one() + half * (gamma - F::one()) * mach.powi(2)).powf((gamma) / (F::one() - gamma))
 }
@@ -78,6 +96,8 @@ pub fn mach_to_p_p0<F: Float>(mach: F, gamma: F) -> F {
 /// assert_eq!(mach_to_rho_rho0(2.0, 1.4), 0.2300481458333117);
 /// ```
 pub fn mach_to_rho_rho0<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
     let half = F::from(0.5).unwrap();
     (F::one() + half * (gamma - F::one()) * mach.powi(2)).powf(F::one() / (F::one() - gamma))
 }
@@ -94,8 +114,221 @@ pub fn mach_to_rho_rho0<F: Float>(mach: F, gamma: F) -> F {
 /// assert_eq!(mach_to_a_ac(2.0, 1.4), 1.6875000000000002);
 /// ```
 pub fn mach_to_a_ac<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    a_ac(mach, gamma)
+}
+
+/// Unchecked critical area ratio, safe to evaluate at solver iterates.
+pub(crate) fn a_ac<F: Float>(mach: F, gamma: F) -> F {
     let half = F::from(0.5).unwrap();
     F::one() / mach
         * ((F::one() + half * (gamma - F::one()) * mach.powi(2)) / (half * (gamma + F::one())))
             .powf(half * (gamma + F::one()) / (gamma - F::one()))
 }
+
+/// Normalised velocity V/sqrt(cp T0) for given mach number and specific heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_to_v_cpt0;
+///
+/// assert_eq!(mach_to_v_cpt0(0.0, 1.4), 0.0);
+/// assert_eq!(mach_to_v_cpt0(1.0, 1.4), 0.5773502691896257);
+/// assert_eq!(mach_to_v_cpt0(2.0, 1.4), 0.9428090415820632);
+/// ```
+pub fn mach_to_v_cpt0<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    let half = F::from(0.5).unwrap();
+    mach * ((gamma - F::one()) / (F::one() + half * (gamma - F::one()) * mach.powi(2))).sqrt()
+}
+
+/// Non-dimensional mass flow function m sqrt(cp T0) / (A p0) for given mach
+/// number and specific heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_to_mcpt0_ap0;
+///
+/// assert_eq!(mach_to_mcpt0_ap0(0.0, 1.4), 0.0);
+/// assert_eq!(mach_to_mcpt0_ap0(1.0, 1.4), 1.2810152558552463);
+/// assert_eq!(mach_to_mcpt0_ap0(2.0, 1.4), 0.7591201516179238);
+/// ```
+pub fn mach_to_mcpt0_ap0<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    let half = F::from(0.5).unwrap();
+    gamma / (gamma - F::one()).sqrt()
+        * mach
+        * (F::one() + half * (gamma - F::one()) * mach.powi(2))
+            .powf(-half * (gamma + F::one()) / (gamma - F::one()))
+}
+
+/// Non-dimensional mass flow function m sqrt(cp T0) / (A p) for given mach
+/// number and specific heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_to_mcpt0_ap;
+///
+/// assert_eq!(mach_to_mcpt0_ap(0.0, 1.4), 0.0);
+/// assert_eq!(mach_to_mcpt0_ap(1.0, 1.4), 2.424871130596428);
+/// assert_eq!(mach_to_mcpt0_ap(2.0, 1.4), 5.939696961966999);
+/// ```
+pub fn mach_to_mcpt0_ap<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    let half = F::from(0.5).unwrap();
+    gamma / (gamma - F::one()).sqrt()
+        * mach
+        * (F::one() + half * (gamma - F::one()) * mach.powi(2)).sqrt()
+}
+
+/// Pitot to static pressure ratio p02/p1 in supersonic flow, from the
+/// Rayleigh pitot formula
+///
+/// The pitot probe sees the stagnation pressure behind the normal shock
+/// standing in front of it.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_to_pitot_p02_p1;
+///
+/// assert_eq!(mach_to_pitot_p02_p1(1.0, 1.4), 1.892929158737854);
+/// assert_eq!(mach_to_pitot_p02_p1(2.0, 1.4), 5.640440812823317);
+/// ```
+pub fn mach_to_pitot_p02_p1<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    pitot_p02_p1(mach, gamma)
+}
+
+/// Unchecked Rayleigh pitot formula, safe to evaluate at solver iterates.
+pub(crate) fn pitot_p02_p1<F: Float>(mach: F, gamma: F) -> F {
+    let two = F::from(2.).unwrap();
+    let gm1 = gamma - F::one();
+    let gp1 = gamma + F::one();
+    (gp1 / two * mach.powi(2)).powf(gamma / gm1)
+        / (two * gamma / gp1 * mach.powi(2) - gm1 / gp1).powf(F::one() / gm1)
+}
+
+/// Characteristic Mach number M* = V/a*, the velocity relative to the sonic
+/// speed of sound
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_to_mach_star;
+///
+/// assert_eq!(mach_to_mach_star(1.0, 1.4), 1.0);
+/// assert_eq!(mach_to_mach_star(2.0, 1.4), 1.632993161855452);
+/// ```
+pub fn mach_to_mach_star<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    let two = F::from(2.).unwrap();
+    ((gamma + F::one()) * mach.powi(2) / (two + (gamma - F::one()) * mach.powi(2))).sqrt()
+}
+
+/// Crocco number C = V/V_max, the velocity relative to the maximum velocity
+/// reached by expanding to zero temperature
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_to_crocco;
+///
+/// assert_eq!(mach_to_crocco(0.0, 1.4), 0.0);
+/// assert_eq!(mach_to_crocco(1.0, 1.4), 0.40824829046386296);
+/// assert_eq!(mach_to_crocco(2.0, 1.4), 0.6666666666666666);
+/// ```
+pub fn mach_to_crocco<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    let two = F::from(2.).unwrap();
+    let m2 = (gamma - F::one()) * mach.powi(2);
+    (m2 / (two + m2)).sqrt()
+}
+
+/// Static to sonic temperature ratio T/T* for given mach number and specific
+/// heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_to_t_tstar;
+///
+/// assert_eq!(mach_to_t_tstar(0.0, 1.4), 1.2);
+/// assert_eq!(mach_to_t_tstar(1.0, 1.4), 1.0);
+/// assert_eq!(mach_to_t_tstar(2.0, 1.4), 0.6666666666666667);
+/// ```
+pub fn mach_to_t_tstar<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    t_tstar(mach, gamma)
+}
+
+/// Static to sonic pressure ratio p/p* for given mach number and specific heat
+/// ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_to_p_pstar;
+///
+/// assert_eq!(mach_to_p_pstar(0.0, 1.4), 1.892929158737854);
+/// assert_eq!(mach_to_p_pstar(1.0, 1.4), 1.0);
+/// assert_eq!(mach_to_p_pstar(2.0, 1.4), 0.24192491286747442);
+/// ```
+pub fn mach_to_p_pstar<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    t_tstar(mach, gamma).powf(gamma / (gamma - F::one()))
+}
+
+/// Static to sonic density ratio rho/rho* for given mach number and specific
+/// heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_to_rho_rhostar;
+///
+/// assert_eq!(mach_to_rho_rhostar(0.0, 1.4), 1.5774409656148785);
+/// assert_eq!(mach_to_rho_rhostar(1.0, 1.4), 1.0);
+/// assert_eq!(mach_to_rho_rhostar(2.0, 1.4), 0.3628873693012116);
+/// ```
+pub fn mach_to_rho_rhostar<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    t_tstar(mach, gamma).powf(F::one() / (gamma - F::one()))
+}
+
+/// Static to sonic speed of sound ratio a/a* for given mach number and
+/// specific heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_to_a_astar;
+///
+/// assert_eq!(mach_to_a_astar(0.0, 1.4), 1.0954451150103321);
+/// assert_eq!(mach_to_a_astar(1.0, 1.4), 1.0);
+/// assert_eq!(mach_to_a_astar(2.0, 1.4), 0.816496580927726);
+/// ```
+pub fn mach_to_a_astar<F: Float>(mach: F, gamma: F) -> F {
+    validate::mach(mach);
+    validate::gamma(gamma);
+    t_tstar(mach, gamma).sqrt()
+}
+
+/// Unchecked static to sonic temperature ratio.
+fn t_tstar<F: Float>(mach: F, gamma: F) -> F {
+    let two = F::from(2.).unwrap();
+    (gamma + F::one()) / (two + (gamma - F::one()) * mach.powi(2))
+}
@@ -1,4 +1,6 @@
 //! Normal Shock relations
+use crate::{mach_to_mach_star, validate, Gas, Quantity};
+use eqsolver::single_variable::FDNewton;
 use num::Float;
 
 /// Mach number after normal shock
@@ -13,6 +15,8 @@ use num::Float;
 ///
 /// ```
 pub fn normal_mach2<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
     let two = F::from(2.).unwrap();
     ((F::one() + (gamma - F::one()) / two * mach.powi(2))
         / (gamma * mach.powi(2) - (gamma - F::one()) / two))
@@ -31,6 +35,13 @@ pub fn normal_mach2<F: Float>(mach: F, gamma: F) -> F {
 ///
 /// ```
 pub fn normal_p02_p01<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    p02_p01(mach, gamma)
+}
+
+/// Unchecked total pressure ratio, safe to evaluate at solver iterates.
+fn p02_p01<F: Float>(mach: F, gamma: F) -> F {
     let two = F::from(2.).unwrap();
     F::one()
         / ((two * gamma / (gamma + F::one()) * mach.powi(2)
@@ -52,6 +63,8 @@ pub fn normal_p02_p01<F: Float>(mach: F, gamma: F) -> F {
 ///
 /// ```
 pub fn normal_p2_p1<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
     F::from(2.).unwrap() * gamma / (gamma + F::one()) * (mach.powi(2) - F::one()) + F::one()
 }
 
@@ -67,6 +80,8 @@ pub fn normal_p2_p1<F: Float>(mach: F, gamma: F) -> F {
 ///
 /// ```
 pub fn normal_rho2_rho1<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
     (gamma + F::one()) * mach.powi(2) / ((gamma - F::one()) * mach.powi(2) + F::from(2.).unwrap())
 }
 
@@ -82,6 +97,8 @@ pub fn normal_rho2_rho1<F: Float>(mach: F, gamma: F) -> F {
 ///
 /// ```
 pub fn normal_t2_t1<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
     let two = F::from(2.).unwrap();
     (two + (gamma - F::one()) * mach.powi(2)) * (two * gamma * mach.powi(2) - (gamma - F::one()))
         / ((gamma + F::one()).powi(2) * mach.powi(2))
@@ -99,8 +116,294 @@ pub fn normal_t2_t1<F: Float>(mach: F, gamma: F) -> F {
 ///
 /// ```
 pub fn normal_a2_a1<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
     let two = F::from(2.).unwrap();
     ((two + (gamma - F::one()) * mach.powi(2)) * (two * gamma * mach.powi(2) - (gamma - F::one()))
         / ((gamma + F::one()).powi(2) * mach.powi(2)))
     .sqrt()
 }
+
+/// Characteristic Mach number after normal shock, from the Prandtl relation
+/// M1* M2* = 1
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{mach_from_mach_star, normal_mach2, normal_mach_star2};
+///
+/// assert_eq!(normal_mach_star2(2.0_f64, 1.4), 0.6123724356957945);
+///
+/// // The Prandtl relation reproduces the downstream Mach number.
+/// let mach2 = mach_from_mach_star(normal_mach_star2(2.0_f64, 1.4), 1.4);
+/// assert!((mach2 - normal_mach2(2.0, 1.4)).abs() < 1e-15);
+/// ```
+pub fn normal_mach_star2<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    mach_to_mach_star(mach, gamma).recip()
+}
+
+/// Upstream Mach number for a given static pressure ratio across normal shock
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_from_normal_p2_p1;
+///
+/// assert_eq!(mach_from_normal_p2_p1(1.0_f64, 1.4), 1.0);
+/// assert_eq!(mach_from_normal_p2_p1(4.5_f64, 1.4), 2.0);
+/// ```
+pub fn mach_from_normal_p2_p1<F: Float>(p2_p1: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    let two = F::from(2.).unwrap();
+    (F::one() + (gamma + F::one()) / (two * gamma) * (p2_p1 - F::one())).sqrt()
+}
+
+/// Upstream Mach number for a given static density ratio across normal shock
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_from_normal_rho2_rho1;
+///
+/// assert_eq!(mach_from_normal_rho2_rho1(1.0_f64, 1.4), 1.0);
+/// assert_eq!(mach_from_normal_rho2_rho1(2.6666666666666665_f64, 1.4), 1.9999999999999998);
+/// ```
+pub fn mach_from_normal_rho2_rho1<F: Float>(rho2_rho1: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    let two = F::from(2.).unwrap();
+    (two * rho2_rho1 / ((gamma + F::one()) - (gamma - F::one()) * rho2_rho1)).sqrt()
+}
+
+/// Upstream Mach number for a given static temperature ratio across normal
+/// shock
+///
+/// Solves the quadratic in M^2 obtained from the temperature ratio.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_from_normal_t2_t1;
+///
+/// assert_eq!(mach_from_normal_t2_t1(1.0_f64, 1.4), 1.0);
+/// assert_eq!(mach_from_normal_t2_t1(1.6874999999999998_f64, 1.4), 2.0);
+/// ```
+pub fn mach_from_normal_t2_t1<F: Float>(t2_t1: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    let n = |x: f64| F::from(x).unwrap();
+    let gm1 = gamma - F::one();
+    let b = n(4.) * gamma - gm1.powi(2) - t2_t1 * (gamma + F::one()).powi(2);
+    let discriminant = b.powi(2) + n(16.) * gamma * gm1.powi(2);
+    ((discriminant.sqrt() - b) / (n(4.) * gamma * gm1)).sqrt()
+}
+
+/// Upstream Mach number for a given total pressure ratio across normal shock
+///
+/// <div class="warning">
+///
+/// This function uses Newton's method to solve for the Mach number.
+///
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::mach_from_normal_p02_p01;
+///
+/// assert_eq!(mach_from_normal_p02_p01(0.7208738614847454_f64, 1.4), 1.9999999999999996);
+/// assert_eq!(mach_from_normal_p02_p01(0.061716319748617694_f64, 1.4), 5.0);
+/// assert_eq!(mach_from_normal_p02_p01(0.9999_f64, 1.4), 1.043800633122434);
+/// ```
+pub fn mach_from_normal_p02_p01<F: Float>(p02_p01: F, gamma: F) -> F {
+    validate::ratio("p02_p01", p02_p01);
+    validate::gamma(gamma);
+    let f = |m| self::p02_p01(m, gamma) - p02_p01;
+    let x0 = F::from(2.).unwrap();
+    FDNewton::new(f).solve(x0).unwrap_or(F::nan())
+}
+
+/// Entropy rise (s2 - s1)/R across normal shock, equal to -ln(p02/p01)
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::normal_ds_r;
+///
+/// assert_eq!(normal_ds_r(1.0_f64, 1.4), 0.0);
+/// assert_eq!(normal_ds_r(2.0_f64, 1.4), 0.3272911063996436);
+/// ```
+pub fn normal_ds_r<F: Float>(mach: F, gamma: F) -> F {
+    -normal_p02_p01(mach, gamma).ln()
+}
+
+/// Density, velocity and static pressure after a stationary normal shock
+///
+/// The velocity is measured in the shock frame and may be negative for flow
+/// in the negative direction, in which case `u2` is negative too.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{normal_p2_p1, normal_shock_primitive};
+///
+/// let u1 = 2.0 * 1.4_f64.sqrt();
+/// let (rho2, u2, p2) = normal_shock_primitive(1.0, u1, 1.0, 1.4);
+/// assert_eq!(rho2, 2.666666666666667);
+/// assert_eq!(u2, 0.8874119674649423);
+/// assert_eq!(p2, normal_p2_p1(2.0, 1.4));
+/// ```
+pub fn normal_shock_primitive<F: Float>(rho1: F, u1: F, p1: F, gamma: F) -> (F, F, F) {
+    let mach = u1.abs() / (gamma * p1 / rho1).sqrt();
+    let rho2_rho1 = normal_rho2_rho1(mach, gamma);
+    (
+        rho1 * rho2_rho1,
+        u1 / rho2_rho1,
+        p1 * normal_p2_p1(mach, gamma),
+    )
+}
+
+/// All jump ratios across a normal shock
+///
+/// Computes every ratio at once from shared subexpressions, which is cheaper
+/// than calling the individual `normal_*` functions when several are needed.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::NormalShock;
+///
+/// let shock = NormalShock::new(2.0_f64, 1.4);
+/// assert_eq!(shock.mach2(), 0.5773502691896257);
+/// assert_eq!(shock.p2_p1(), 4.5);
+/// assert_eq!(shock.t2_t1(), 1.6874999999999998);
+/// assert_eq!(shock.p02_p01(), 0.7208738614847454);
+/// assert_eq!(shock.p02_p1(), 5.640440812823317);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NormalShock<F> {
+    mach: F,
+    gamma: F,
+    mach2: F,
+    p2_p1: F,
+    rho2_rho1: F,
+    t2_t1: F,
+    p02_p01: F,
+    p02_p1: F,
+}
+
+impl<F: Float> NormalShock<F> {
+    /// Normal shock for a given upstream Mach number.
+    pub fn new(mach: F, gamma: F) -> Self {
+        validate::supersonic(mach);
+        validate::gamma(gamma);
+        let two = F::from(2.).unwrap();
+        let m2 = mach.powi(2);
+        let gm1 = gamma - F::one();
+        let gp1 = gamma + F::one();
+        let stag = F::one() + gm1 / two * m2;
+
+        let p2_p1 = (two * gamma * m2 - gm1) / gp1;
+        let rho2_rho1 = gp1 * m2 / (gm1 * m2 + two);
+        let p02_p01 = rho2_rho1.powf(gamma / gm1) * p2_p1.powf(-F::one() / gm1);
+        Self {
+            mach,
+            gamma,
+            mach2: (stag / (gamma * m2 - gm1 / two)).sqrt(),
+            p2_p1,
+            rho2_rho1,
+            t2_t1: p2_p1 / rho2_rho1,
+            p02_p01,
+            p02_p1: p02_p01 * stag.powf(gamma / gm1),
+        }
+    }
+
+    /// Normal shock in a gas at upstream static temperature `t1`
+    ///
+    /// The specific heat ratio is taken at `t1` and held constant across the
+    /// shock; [`ThermallyPerfectNormalShock`](crate::ThermallyPerfectNormalShock)
+    /// lets it vary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::{gases, NormalShock};
+    ///
+    /// let shock = NormalShock::from_gas(&gases::air(), 2.0_f64, 300.0);
+    /// assert_eq!(shock, NormalShock::new(2.0, 1.4));
+    /// ```
+    pub fn from_gas<G: Gas<F>>(gas: &G, mach: F, t1: F) -> Self {
+        Self::new(mach, gas.gamma(t1))
+    }
+
+    /// Upstream Mach number.
+    pub fn mach(&self) -> F {
+        self.mach
+    }
+
+    /// Specific heat ratio.
+    pub fn gamma(&self) -> F {
+        self.gamma
+    }
+
+    /// Mach number after the shock.
+    pub fn mach2(&self) -> F {
+        self.mach2
+    }
+
+    /// Static pressure ratio across the shock.
+    pub fn p2_p1(&self) -> F {
+        self.p2_p1
+    }
+
+    /// Static density ratio across the shock.
+    pub fn rho2_rho1(&self) -> F {
+        self.rho2_rho1
+    }
+
+    /// Static temperature ratio across the shock.
+    pub fn t2_t1(&self) -> F {
+        self.t2_t1
+    }
+
+    /// Speed of sound ratio across the shock.
+    pub fn a2_a1(&self) -> F {
+        self.t2_t1.sqrt()
+    }
+
+    /// Stagnation pressure ratio across the shock.
+    pub fn p02_p01(&self) -> F {
+        self.p02_p01
+    }
+
+    /// Ratio of stagnation pressure after the shock to static pressure before
+    /// it, as measured by a pitot probe in supersonic flow.
+    pub fn p02_p1(&self) -> F {
+        self.p02_p1
+    }
+
+    /// Every property of the shock, labelled with its registered quantity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::NormalShock;
+    ///
+    /// let shock = NormalShock::new(2.0_f64, 1.4);
+    /// let (quantity, value) = shock.quantities()[3];
+    /// assert_eq!(quantity.symbol, "p2/p1");
+    /// assert_eq!(value, 4.5);
+    /// ```
+    pub fn quantities(&self) -> Vec<(&'static Quantity, F)> {
+        vec![
+            (&Quantity::MACH, self.mach),
+            (&Quantity::GAMMA, self.gamma),
+            (&Quantity::MACH2, self.mach2),
+            (&Quantity::P2_P1, self.p2_p1),
+            (&Quantity::RHO2_RHO1, self.rho2_rho1),
+            (&Quantity::T2_T1, self.t2_t1),
+            (&Quantity::A2_A1, self.a2_a1()),
+            (&Quantity::P02_P01, self.p02_p01),
+            (&Quantity::P02_P1, self.p02_p1),
+        ]
+    }
+}
@@ -0,0 +1,320 @@
+//! Rocket nozzle performance and plug nozzle contours
+//!
+//! Nozzle flow is taken to be isentropic and one-dimensional from a chamber at
+//! stagnation pressure p0, with the exit state fixed by the design exit Mach
+//! number. Pressures are given relative to the chamber pressure.
+use crate::{
+    mach_from_p_p0, mach_to_a_ac, mach_to_mach_angle, mach_to_mcpt0_ap0, mach_to_p_p0,
+    mach_to_pm_angle, standard_atmosphere, validate,
+};
+use num::Float;
+
+/// Thrust coefficient F / (p0 A*) of a fixed nozzle with a given exit Mach
+/// number and ambient pressure ratio pa/p0
+///
+/// Includes the pressure thrust (pe - pa) Ae, so it is valid for under- and
+/// overexpanded nozzles as long as the flow stays attached.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{mach_to_p_p0, thrust_coefficient};
+///
+/// // Vacuum and matched thrust coefficients of a Mach 3 nozzle.
+/// assert_eq!(thrust_coefficient(3.0_f64, 1.4, 0.0), 1.5678153054540658);
+/// let pe = mach_to_p_p0(3.0, 1.4);
+/// assert_eq!(thrust_coefficient(3.0, 1.4, pe), 1.4525347682883256);
+/// ```
+pub fn thrust_coefficient<F: Float>(mach_exit: F, gamma: F, pa_p0: F) -> F {
+    validate::mach(mach_exit);
+    validate::gamma(gamma);
+    let two = F::from(2.).unwrap();
+    let gp1 = gamma + F::one();
+    let gm1 = gamma - F::one();
+    let pe_p0 = mach_to_p_p0(mach_exit, gamma);
+    let momentum = (two * gamma.powi(2) / gm1
+        * (two / gp1).powf(gp1 / gm1)
+        * (F::one() - pe_p0.powf(gm1 / gamma)))
+    .sqrt();
+    momentum + (pe_p0 - pa_p0) * mach_to_a_ac(mach_exit, gamma)
+}
+
+/// Standard acceleration of gravity used to express specific impulse in seconds.
+pub(crate) const STANDARD_GRAVITY: f64 = 9.80665;
+
+/// Performance of a fixed nozzle at one altitude
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AltitudePerformance<F> {
+    /// Geopotential altitude.
+    pub altitude: F,
+    /// Ambient pressure from the standard atmosphere.
+    pub pa: F,
+    /// Thrust.
+    pub thrust: F,
+    /// Specific impulse in seconds.
+    pub isp: F,
+    /// Whether the exit pressure is below the Summerfield separation limit of
+    /// 0.4 pa, in which case the attached-flow thrust is unreliable.
+    pub separated: bool,
+}
+
+/// Thrust, specific impulse and separation risk of a fixed nozzle against
+/// altitude in the standard atmosphere
+///
+/// The chamber is at stagnation pressure `p0` and temperature `t0`, `r` is the
+/// specific gas constant of the exhaust and the flow is frozen at `gamma`.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::altitude_performance;
+///
+/// // 7 MPa chamber with a 0.01 m^2 throat and Mach 4 exit.
+/// let altitudes = [0.0_f64, 10_000.0, 50_000.0];
+/// let table = altitude_performance(7.0e6, 3300.0, 0.01, 4.0, 1.2, 350.0, &altitudes);
+/// assert_eq!(table[0].thrust, 101059.55246654931);
+/// assert_eq!(table[2].isp, 313.26533194624534);
+/// assert!(table[0].separated);
+/// assert!(!table[1].separated);
+/// ```
+pub fn altitude_performance<F: Float>(
+    p0: F,
+    t0: F,
+    throat_area: F,
+    mach_exit: F,
+    gamma: F,
+    r: F,
+    altitudes: &[F],
+) -> Vec<AltitudePerformance<F>> {
+    validate::gamma(gamma);
+    let cp = gamma * r / (gamma - F::one());
+    let mass_flow = mach_to_mcpt0_ap0(F::one(), gamma) * throat_area * p0 / (cp * t0).sqrt();
+    let pe = p0 * mach_to_p_p0(mach_exit, gamma);
+    let limit = F::from(0.4).unwrap();
+    altitudes
+        .iter()
+        .map(|&altitude| {
+            let pa = standard_atmosphere(altitude).p;
+            let thrust = thrust_coefficient(mach_exit, gamma, pa / p0) * p0 * throat_area;
+            AltitudePerformance {
+                altitude,
+                pa,
+                thrust,
+                isp: thrust / (mass_flow * F::from(STANDARD_GRAVITY).unwrap()),
+                separated: pe < limit * pa,
+            }
+        })
+        .collect()
+}
+
+/// Flow restriction in the liquid feed line of a pressure-fed engine
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FeedRestriction<F> {
+    /// Sharp-edged orifice, whose flow depends on the pressure drop to the
+    /// chamber.
+    Orifice {
+        /// Discharge coefficient.
+        cd: F,
+        /// Orifice area.
+        area: F,
+    },
+    /// Cavitating venturi, whose flow depends only on the feed and vapour
+    /// pressures while the throat cavitates.
+    CavitatingVenturi {
+        /// Discharge coefficient.
+        cd: F,
+        /// Throat area.
+        area: F,
+        /// Vapour pressure of the propellant.
+        vapour_pressure: F,
+        /// Largest ratio of chamber to feed pressure at which the venturi
+        /// stays cavitating, typically 0.8 to 0.85.
+        recovery: F,
+    },
+}
+
+/// Operating point of a pressure-fed engine
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThrottlePoint<F> {
+    /// Feed pressure upstream of the restriction.
+    pub feed_pressure: F,
+    /// Propellant mass flow rate.
+    pub mass_flow: F,
+    /// Chamber pressure.
+    pub p0: F,
+    /// Thrust.
+    pub thrust: F,
+    /// Whether the feed restriction is a cavitating venturi that is choked.
+    pub cavitating: bool,
+}
+
+/// Chamber pressure and thrust of a pressure-fed engine against feed
+/// pressure
+///
+/// The propellant of density `rho` passes the feed restriction into a chamber
+/// with characteristic velocity `c_star`, so that p0 = mdot c* / A*. The
+/// nozzle has throat area `throat_area` and exit Mach number `mach_exit`, and
+/// exhausts at `gamma` into ambient pressure `pa`. A venturi that would
+/// exceed its pressure recovery is treated as an orifice of the same area.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{throttle_map, FeedRestriction};
+///
+/// let venturi = FeedRestriction::CavitatingVenturi {
+///     cd: 0.95,
+///     area: 1.0e-5_f64,
+///     vapour_pressure: 2.0e3,
+///     recovery: 0.85,
+/// };
+/// let feed = [1.0e6, 2.0e6, 3.0e6];
+/// let map = throttle_map(venturi, 1000.0, 1500.0, 1.0e-3, 3.0, 1.2, 0.0, &feed);
+/// assert!(map.iter().all(|point| point.cavitating));
+/// assert_eq!(map[1].p0, 900798.3958689092);
+/// assert_eq!(map[1].thrust, 1521.7797048778777);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn throttle_map<F: Float>(
+    restriction: FeedRestriction<F>,
+    rho: F,
+    c_star: F,
+    throat_area: F,
+    mach_exit: F,
+    gamma: F,
+    pa: F,
+    feed_pressures: &[F],
+) -> Vec<ThrottlePoint<F>> {
+    let two = F::from(2.).unwrap();
+    // Chamber pressure fed through an orifice with mdot = k sqrt(p_feed - p0).
+    let orifice = |k: F, feed: F| {
+        let c2 = (k * c_star / throat_area).powi(2);
+        let p0 = (-c2 + (c2.powi(2) + F::from(4.).unwrap() * c2 * feed).sqrt()) / two;
+        (k * (feed - p0).sqrt(), p0)
+    };
+    feed_pressures
+        .iter()
+        .map(|&feed_pressure| {
+            let (mass_flow, cavitating) = match restriction {
+                FeedRestriction::Orifice { cd, area } => (
+                    orifice(cd * area * (two * rho).sqrt(), feed_pressure).0,
+                    false,
+                ),
+                FeedRestriction::CavitatingVenturi {
+                    cd,
+                    area,
+                    vapour_pressure,
+                    recovery,
+                } => {
+                    let k = cd * area * (two * rho).sqrt();
+                    let choked = k * (feed_pressure - vapour_pressure).sqrt();
+                    if choked * c_star / throat_area <= recovery * feed_pressure {
+                        (choked, true)
+                    } else {
+                        (orifice(k, feed_pressure).0, false)
+                    }
+                }
+            };
+            let p0 = mass_flow * c_star / throat_area;
+            ThrottlePoint {
+                feed_pressure,
+                mass_flow,
+                p0,
+                thrust: thrust_coefficient(mach_exit, gamma, pa / p0) * p0 * throat_area,
+                cavitating,
+            }
+        })
+        .collect()
+}
+
+/// Point on an aerospike plug contour
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AerospikePoint<F> {
+    /// Axial distance downstream of the cowl lip, relative to the lip radius.
+    pub x: F,
+    /// Plug radius relative to the lip radius.
+    pub r: F,
+    /// Mach number at the plug surface.
+    pub mach: F,
+    /// Surface static pressure relative to the chamber pressure.
+    pub p_p0: F,
+}
+
+/// Approximate contour of a full-length annular aerospike plug
+///
+/// Uses Angelino's method: the flow turns through a centred Prandtl-Meyer
+/// expansion at the cowl lip, and each Mach line of the fan meets the plug
+/// where the annulus between lip and plug passes the isentropic area ratio
+/// for its Mach number. The contour is returned at `n` Mach numbers from
+/// sonic at the throat to `mach_exit` at the plug tip, where the radius
+/// reaches zero.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::aerospike_contour;
+///
+/// let contour = aerospike_contour(3.0_f64, 1.4, 11);
+/// let throat = contour[0];
+/// let tip = contour[10];
+/// assert_eq!(throat.x, -0.09385682657004618);
+/// assert_eq!(throat.r, 0.920564988727915);
+/// assert!(tip.r.abs() < 1e-7);
+/// assert_eq!(tip.x, 2.8284271247461903);
+/// assert_eq!(tip.p_p0, 0.027223683703862824);
+/// ```
+pub fn aerospike_contour<F: Float>(mach_exit: F, gamma: F, n: usize) -> Vec<AerospikePoint<F>> {
+    validate::supersonic(mach_exit);
+    validate::gamma(gamma);
+    let epsilon = mach_to_a_ac(mach_exit, gamma);
+    let nu_exit = mach_to_pm_angle(mach_exit, gamma);
+    let steps = F::from(n.max(2) - 1).unwrap();
+    (0..n.max(2))
+        .map(|i| {
+            let mach = F::one() + (mach_exit - F::one()) * F::from(i).unwrap() / steps;
+            // Inclination of the Mach line from the lip to the plug.
+            let mu = mach_to_mach_angle(mach);
+            let alpha = nu_exit - mach_to_pm_angle(mach, gamma) + mu;
+            let r = (F::one() - mach_to_a_ac(mach, gamma) * alpha.sin() / (epsilon * mu.sin()))
+                .max(F::zero())
+                .sqrt();
+            AerospikePoint {
+                x: (F::one() - r) / alpha.tan(),
+                r,
+                mach,
+                p_p0: mach_to_p_p0(mach, gamma),
+            }
+        })
+        .collect()
+}
+
+/// Thrust coefficient F / (p0 A*) of an ideal full-length aerospike with a
+/// given design exit Mach number and ambient pressure ratio pa/p0
+///
+/// Below the design altitude the free jet boundary adapts to the ambient
+/// pressure, so the plug performs like a fixed nozzle expanding exactly to
+/// ambient; above it the plug behaves like a fixed nozzle of the design area
+/// ratio. Base and plug-truncation effects are neglected.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{aerospike_thrust_coefficient, thrust_coefficient};
+///
+/// // At sea level a Mach 4 design pressure ratio outperforms a bell nozzle.
+/// let pa_p0 = 1.0 / 70.0_f64;
+/// let spike = aerospike_thrust_coefficient(4.0, 1.2, pa_p0);
+/// let bell = thrust_coefficient(4.0, 1.2, pa_p0);
+/// assert!(spike > bell);
+/// assert_eq!(spike, 1.600301918927539);
+/// assert_eq!(aerospike_thrust_coefficient(4.0, 1.2, 0.0), thrust_coefficient(4.0, 1.2, 0.0));
+/// ```
+pub fn aerospike_thrust_coefficient<F: Float>(mach_exit: F, gamma: F, pa_p0: F) -> F {
+    validate::mach(mach_exit);
+    validate::gamma(gamma);
+    if pa_p0 > mach_to_p_p0(mach_exit, gamma) {
+        thrust_coefficient(mach_from_p_p0(pa_p0, gamma), gamma, pa_p0)
+    } else {
+        thrust_coefficient(mach_exit, gamma, pa_p0)
+    }
+}
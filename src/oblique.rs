@@ -1,7 +1,35 @@
-//! Weak oblique shock functions
+//! Oblique shock functions
+//!
+//! For a given deflection there are two shock solutions. The plain `oblique_*`
+//! functions use the weak solution, which is usually observed in practice; the
+//! `oblique_*_strong` variants use the strong solution, which has subsonic
+//! downstream flow and arises e.g. for blunt bodies or high back pressure.
 
+use crate::{
+    mach_from_normal_p2_p1, mach_to_p_p0, normal_shock_primitive, validate, Gas, Quantity,
+};
 use eqsolver::single_variable::FDNewton;
 use num::Float;
+use std::{error::Error, fmt};
+
+/// Error returned when the flow deflection is too large for an attached
+/// oblique shock.
+///
+/// Beyond the maximum deflection angle no oblique shock solution exists and
+/// the shock stands off the body as a detached bow shock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShockDetached;
+
+impl fmt::Display for ShockDetached {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "deflection exceeds the maximum for an attached oblique shock"
+        )
+    }
+}
+
+impl Error for ShockDetached {}
 
 /// Wave angle for weak oblique shock
 ///
@@ -10,51 +38,228 @@ use num::Float;
 /// ```
 /// use comp_flow::oblique_beta;
 ///
-/// assert_eq!(oblique_beta(2.0_f32, 1.4_f32, 0.1745329_f32), 0.6861576);
-/// assert_eq!(oblique_beta(5.0_f64, 1.4_f64, 0.3490659_f64), 0.5201241529003784);
+/// assert_eq!(oblique_beta(2.0_f32, 1.4_f32, 0.1745329_f32), 0.6861575);
+/// assert_eq!(oblique_beta(5.0_f64, 1.4_f64, 0.3490659_f64), 0.5201241529004286);
 ///
 ///
 /// ```
 pub fn oblique_beta<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
     let beta_max: F = oblique_beta_max(mach, gamma);
+    let mach_angle = (F::one() / mach).asin();
+    let step = F::from(0.1).unwrap();
+    let f = theta_beta_residual(mach, gamma, theta);
+
+    // The residual is flat at beta_max, so step down until Newton converges
+    // onto the weak root.
     let mut x0 = beta_max;
-    let two = F::from(2.0).unwrap();
+    while x0 >= mach_angle {
+        if let Ok(beta) = FDNewton::new(f).solve(x0) {
+            if beta >= F::zero() && beta <= beta_max {
+                return beta;
+            }
+        }
+        x0 = x0 - step;
+    }
 
-    let f = |x: F| {
-        theta.tan()
-            - two / x.tan() * (mach.powi(2) * x.sin().powi(2) - F::one())
-                / (mach.powi(2) * (gamma + (two * x).cos()) + two)
-    };
-    let beta_result = FDNewton::new(f).solve(x0);
-    let mut beta = match beta_result {
-        Ok(x) => x,
-        Err(_) => return F::nan(),
-    };
+    F::nan()
+}
+
+/// Wave angle for strong oblique shock
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_beta_strong;
+///
+/// assert_eq!(oblique_beta_strong(2.0_f32, 1.4_f32, 0.1745329_f32), 1.460842);
+/// assert_eq!(oblique_beta_strong(5.0_f64, 1.4_f64, 0.3490659_f64), 1.4757850342802814);
+/// ```
+pub fn oblique_beta_strong<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
+    let beta_max: F = oblique_beta_max(mach, gamma);
+    let half_pi = F::from(std::f64::consts::FRAC_PI_2).unwrap();
+    let step = F::from(0.05).unwrap();
+    let f = theta_beta_residual(mach, gamma, theta);
 
-    while (beta > beta_max) || (beta < F::zero()) {
-        x0 = x0 - F::from(0.1).unwrap();
-        let beta_result = FDNewton::new(f).solve(x0);
-        beta = match beta_result {
-            Ok(x) => x,
-            Err(_) => return F::nan(),
-        };
+    let mut x0 = half_pi;
+    while x0 >= beta_max {
+        if let Ok(beta) = FDNewton::new(f).solve(x0) {
+            if beta >= beta_max && beta <= half_pi {
+                return beta;
+            }
+        }
+        x0 = x0 - step;
     }
 
-    beta
+    F::nan()
+}
+
+/// Wave angle for weak oblique shock, or an error if the shock detaches
+///
+/// Returns [`ShockDetached`] when the deflection exceeds the maximum deflection
+/// for an attached shock at this Mach number, in which case a detached bow
+/// shock forms and [`oblique_beta`] would return NaN.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{oblique_beta_checked, ShockDetached};
+///
+/// assert_eq!(oblique_beta_checked(2.0_f32, 1.4_f32, 0.1745329_f32), Ok(0.6861575));
+/// assert_eq!(oblique_beta_checked(2.0_f64, 1.4_f64, 0.5_f64), Err(ShockDetached));
+/// ```
+pub fn oblique_beta_checked<F: Float>(mach: F, gamma: F, theta: F) -> Result<F, ShockDetached> {
+    attached(mach, gamma, theta)?;
+    Ok(oblique_beta(mach, gamma, theta))
+}
+
+/// Wave angle for strong oblique shock, or an error if the shock detaches
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{oblique_beta_strong_checked, ShockDetached};
+///
+/// assert_eq!(oblique_beta_strong_checked(2.0_f32, 1.4_f32, 0.1745329_f32), Ok(1.460842));
+/// assert_eq!(oblique_beta_strong_checked(2.0_f64, 1.4_f64, 0.5_f64), Err(ShockDetached));
+/// ```
+pub fn oblique_beta_strong_checked<F: Float>(
+    mach: F,
+    gamma: F,
+    theta: F,
+) -> Result<F, ShockDetached> {
+    attached(mach, gamma, theta)?;
+    Ok(oblique_beta_strong(mach, gamma, theta))
 }
 
 /// Maximum oblique shock angle
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_beta_max;
+///
+/// assert_eq!(oblique_beta_max(2.0_f32, 1.4_f32), 1.1286867);
+/// assert_eq!(oblique_beta_max(5.0_f64, 1.4_f64), 1.162114286948938);
+/// ```
 pub fn oblique_beta_max<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
     ((F::one() / (gamma * mach.powi(2))
         * (((gamma + F::one()) / F::from(4.0).unwrap() * mach.powi(2)) - F::one()
-            + ((gamma + F::one()) * (gamma + F::one()) / F::from(16.0).unwrap() * mach.powi(4)
-                + (gamma - F::one()) / F::from(2.0).unwrap() * mach.powi(2)
-                + F::one())
+            + ((gamma + F::one())
+                * ((gamma + F::one()) / F::from(16.0).unwrap() * mach.powi(4)
+                    + (gamma - F::one()) / F::from(2.0).unwrap() * mach.powi(2)
+                    + F::one()))
             .sqrt()))
     .sqrt())
     .asin()
 }
 
+/// Maximum flow deflection angle for an attached oblique shock
+///
+/// This is the deflection at [`oblique_beta_max`]. Larger deflections produce a
+/// detached bow shock.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_theta_max;
+///
+/// assert_eq!(oblique_theta_max(2.0_f32, 1.4_f32), 0.4009638);
+/// assert_eq!(oblique_theta_max(5.0_f64, 1.4_f64), 0.7176386018190468);
+/// ```
+pub fn oblique_theta_max<F: Float>(mach: F, gamma: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    oblique_theta(mach, gamma, oblique_beta_max(mach, gamma))
+}
+
+/// Flow deflection angle for a given wave angle
+///
+/// Explicit form of the theta-beta-mach relation, valid for both the weak and
+/// the strong solution.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_theta;
+///
+/// assert_eq!(oblique_theta(2.0_f32, 1.4_f32, 0.6861575_f32), 0.17453292);
+/// assert_eq!(oblique_theta(5.0_f64, 1.4_f64, 0.5201241529004286_f64), 0.3490659);
+/// ```
+pub fn oblique_theta<F: Float>(mach: F, gamma: F, beta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let two = F::from(2.0).unwrap();
+    (two / beta.tan() * (mach.powi(2) * beta.sin().powi(2) - F::one())
+        / (mach.powi(2) * (gamma + (two * beta).cos()) + two))
+        .atan()
+}
+
+/// Upstream Mach number for a given wave angle and flow deflection angle
+///
+/// Closed-form inversion of the theta-beta-mach relation, e.g. for a wave
+/// angle measured from a schlieren image of flow over a wedge.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_mach_from_beta_theta;
+///
+/// assert_eq!(oblique_mach_from_beta_theta(0.6861575_f32, 0.1745329_f32, 1.4_f32), 2.0);
+/// assert_eq!(oblique_mach_from_beta_theta(0.5201241529004286_f64, 0.3490659_f64, 1.4_f64), 4.999999999999999);
+/// ```
+pub fn oblique_mach_from_beta_theta<F: Float>(beta: F, theta: F, gamma: F) -> F {
+    validate::gamma(gamma);
+    validate::deflection(theta);
+    let two = F::from(2.0).unwrap();
+    (two * (F::one() / beta.tan() + theta.tan())
+        / ((two * beta).sin() - theta.tan() * (gamma + (two * beta).cos())))
+    .sqrt()
+}
+
+/// Wave angle for a given static pressure ratio across an oblique shock
+///
+/// The pressure ratio fixes the normal Mach number, and with it a unique wave
+/// angle between the Mach angle and 90 degrees, so no weak or strong choice
+/// is needed.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_beta_from_p2_p1;
+///
+/// assert_eq!(oblique_beta_from_p2_p1(2.0_f64, 1.4, 1.7065786), 0.6861575517224224);
+/// ```
+pub fn oblique_beta_from_p2_p1<F: Float>(mach: F, gamma: F, p2_p1: F) -> F {
+    validate::supersonic(mach);
+    (mach_from_normal_p2_p1(p2_p1, gamma) / mach).asin()
+}
+
+/// Flow deflection angle for a given static pressure ratio across an oblique
+/// shock
+///
+/// Recovers the wedge or ramp angle implied by a static pressure measurement
+/// behind the shock.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_theta_from_p2_p1;
+///
+/// assert_eq!(oblique_theta_from_p2_p1(2.0_f64, 1.4, 1.7065786), 0.17453292439655574);
+/// assert_eq!(oblique_theta_from_p2_p1(5.0_f64, 1.4, 7.03741101750251), 0.3490658999999998);
+/// ```
+pub fn oblique_theta_from_p2_p1<F: Float>(mach: F, gamma: F, p2_p1: F) -> F {
+    oblique_theta(mach, gamma, oblique_beta_from_p2_p1(mach, gamma, p2_p1))
+}
+
 /// Mach number after weak oblique shock
 ///
 /// # Examples
@@ -62,19 +267,16 @@ pub fn oblique_beta_max<F: Float>(mach: F, gamma: F) -> F {
 /// ```
 /// use comp_flow::oblique_mach2;
 ///
-/// assert_eq!(oblique_mach2(2.0_f32, 1.4_f32, 0.1745329_f32), 1.6405221);
-/// assert_eq!(oblique_mach2(5.0_f64, 1.4_f64, 0.3490659_f64), 3.022151379742916);
+/// assert_eq!(oblique_mach2(2.0_f32, 1.4_f32, 0.1745329_f32), 1.6405222);
+/// assert_eq!(oblique_mach2(5.0_f64, 1.4_f64, 0.3490659_f64), 3.02215137974268);
 ///
 /// ```
 pub fn oblique_mach2<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
     let beta = oblique_beta(mach, gamma, theta);
-    let two = F::from(2.).unwrap();
-
-    ((F::one() + (gamma - F::one()) / two * mach.powi(2))
-        / (gamma * mach.powi(2) * beta.sin().powi(2) - (gamma - F::one()) / two)
-        + (mach.powi(2) * beta.cos().powi(2))
-            / (F::one() + (gamma - F::one()) / two * mach.powi(2) * beta.sin().powi(2)))
-    .sqrt()
+    mach2_beta(mach, gamma, beta)
 }
 
 /// Stagnation pressure ratio across weak oblique shock
@@ -84,21 +286,16 @@ pub fn oblique_mach2<F: Float>(mach: F, gamma: F, theta: F) -> F {
 /// ```
 /// use comp_flow::oblique_p02_p01;
 ///
-/// assert_eq!(oblique_p02_p01(2.0_f32, 1.4_f32, 0.1745329_f32), 0.98464406);
-/// assert_eq!(oblique_p02_p01(5.0_f64, 1.4_f64, 0.3490659_f64), 0.5050701357775494);
+/// assert_eq!(oblique_p02_p01(2.0_f32, 1.4_f32, 0.1745329_f32), 0.9846444);
+/// assert_eq!(oblique_p02_p01(5.0_f64, 1.4_f64, 0.3490659_f64), 0.5050701357774614);
 ///
 /// ```
 pub fn oblique_p02_p01<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
     let beta = oblique_beta(mach, gamma, theta);
-    let mach1n = mach * beta.sin();
-    let two = F::from(2.).unwrap();
-
-    F::one()
-        / ((two * gamma / (gamma + F::one()) * mach1n.powi(2)
-            - (gamma - F::one()) / (gamma + F::one()))
-        .powf(F::one() / (gamma - F::one()))
-            * (two / (gamma + F::one()) / mach1n.powi(2) + (gamma - F::one()) / (gamma + F::one()))
-                .powf(gamma / (gamma - F::one())))
+    p02_p01_beta(mach, gamma, beta)
 }
 
 /// Static pressure ratio across weak oblique shock
@@ -108,14 +305,16 @@ pub fn oblique_p02_p01<F: Float>(mach: F, gamma: F, theta: F) -> F {
 /// ```
 /// use comp_flow::oblique_p2_p1;
 ///
-/// assert_eq!(oblique_p2_p1(2.0_f32, 1.4_f32, 0.1745329_f32), 1.7065787);
-/// assert_eq!(oblique_p2_p1(5.0_f64, 1.4_f64, 0.3490659_f64), 7.037411017501249);
+/// assert_eq!(oblique_p2_p1(2.0_f32, 1.4_f32, 0.1745329_f32), 1.7065785);
+/// assert_eq!(oblique_p2_p1(5.0_f64, 1.4_f64, 0.3490659_f64), 7.03741101750251);
 ///
 /// ```
 pub fn oblique_p2_p1<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
     let beta = oblique_beta(mach, gamma, theta);
-    let mach1n = mach * beta.sin();
-    F::from(2.).unwrap() * gamma / (gamma + F::one()) * (mach1n.powi(2) - F::one()) + F::one()
+    p2_p1_beta(mach, gamma, beta)
 }
 
 /// Static density ratio across weak oblique shock
@@ -125,15 +324,16 @@ pub fn oblique_p2_p1<F: Float>(mach: F, gamma: F, theta: F) -> F {
 /// ```
 /// use comp_flow::oblique_rho2_rho1;
 ///
-/// assert_eq!(oblique_rho2_rho1(2.0_f32, 1.4_f32, 0.1745329_f32), 1.4584259);
-/// assert_eq!(oblique_rho2_rho1(5.0_f64, 1.4_f64, 0.3490659_f64), 3.3154179190165545);
+/// assert_eq!(oblique_rho2_rho1(2.0_f32, 1.4_f32, 0.1745329_f32), 1.4584255);
+/// assert_eq!(oblique_rho2_rho1(5.0_f64, 1.4_f64, 0.3490659_f64), 3.3154179190168143);
 ///
 /// ```
 pub fn oblique_rho2_rho1<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
     let beta = oblique_beta(mach, gamma, theta);
-    let mach1n = mach * beta.sin();
-    (gamma + F::one()) * mach1n.powi(2)
-        / ((gamma - F::one()) * mach1n.powi(2) + F::from(2.).unwrap())
+    rho2_rho1_beta(mach, gamma, beta)
 }
 
 /// Static temperature ratio across weak oblique shock
@@ -144,16 +344,15 @@ pub fn oblique_rho2_rho1<F: Float>(mach: F, gamma: F, theta: F) -> F {
 /// use comp_flow::oblique_t2_t1;
 ///
 /// assert_eq!(oblique_t2_t1(2.0_f32, 1.4_f32, 0.1745329_f32), 1.17015128);
-/// assert_eq!(oblique_t2_t1(5.0_f64, 1.4_f64, 0.3490659_f64), 2.122631652901466);
+/// assert_eq!(oblique_t2_t1(5.0_f64, 1.4_f64, 0.3490659_f64), 2.1226316529016804);
 ///
 /// ```
 pub fn oblique_t2_t1<F: Float>(mach: F, gamma: F, theta: F) -> F {
-    let two = F::from(2.).unwrap();
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
     let beta = oblique_beta(mach, gamma, theta);
-    let mach1n = mach * beta.sin();
-    (two + (gamma - F::one()) * mach1n.powi(2))
-        * (two * gamma * mach1n.powi(2) - (gamma - F::one()))
-        / ((gamma + F::one()).powi(2) * mach1n.powi(2))
+    t2_t1_beta(mach, gamma, beta)
 }
 
 /// Speed of sound ratio across weak oblique shock
@@ -164,12 +363,552 @@ pub fn oblique_t2_t1<F: Float>(mach: F, gamma: F, theta: F) -> F {
 /// use comp_flow::oblique_a2_a1;
 ///
 /// assert_eq!(oblique_a2_a1(2.0_f32, 1.4_f32, 0.1745329_f32), 1.08173530);
-/// assert_eq!(oblique_a2_a1(5.0_f64, 1.4_f64, 0.3490659_f64), 1.4569254108915342);
+/// assert_eq!(oblique_a2_a1(5.0_f64, 1.4_f64, 0.3490659_f64), 1.4569254108916079);
 ///
 /// ```
 pub fn oblique_a2_a1<F: Float>(mach: F, gamma: F, theta: F) -> F {
-    let two = F::from(2.).unwrap();
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
     let beta = oblique_beta(mach, gamma, theta);
+    a2_a1_beta(mach, gamma, beta)
+}
+
+/// Entropy rise (s2 - s1)/R across weak oblique shock
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_ds_r;
+///
+/// assert_eq!(oblique_ds_r(2.0_f64, 1.4_f64, 0.1745329_f64), 0.015475095243498917);
+/// ```
+pub fn oblique_ds_r<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    -oblique_p02_p01(mach, gamma, theta).ln()
+}
+
+/// Total pressure loss coefficient (p01 - p02)/(p01 - p1) across weak oblique
+/// shock
+///
+/// The loss is referred to the upstream dynamic head p01 - p1, so losses of
+/// successive shocks in an inlet can be compared directly.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_loss_coefficient;
+///
+/// assert_eq!(oblique_loss_coefficient(2.0_f64, 1.4_f64, 0.1745329_f64), 0.01760611202666442);
+/// ```
+pub fn oblique_loss_coefficient<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    loss_coefficient(mach, gamma, oblique_p02_p01(mach, gamma, theta))
+}
+
+/// Loss coefficient from the stagnation pressure ratio of a shock.
+fn loss_coefficient<F: Float>(mach: F, gamma: F, p02_p01: F) -> F {
+    (F::one() - p02_p01) / (F::one() - mach_to_p_p0(mach, gamma))
+}
+
+/// Mach number after strong oblique shock
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_mach2_strong;
+///
+/// assert_eq!(oblique_mach2_strong(2.0_f32, 1.4_f32, 0.1745329_f32), 0.6036976);
+/// assert_eq!(oblique_mach2_strong(5.0_f64, 1.4_f64, 0.3490659_f64), 0.4601870633619037);
+/// ```
+pub fn oblique_mach2_strong<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
+    let beta = oblique_beta_strong(mach, gamma, theta);
+    mach2_beta(mach, gamma, beta)
+}
+
+/// Stagnation pressure ratio across strong oblique shock
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_p02_p01_strong;
+///
+/// assert_eq!(oblique_p02_p01_strong(2.0_f32, 1.4_f32, 0.1745329_f32), 0.72651565);
+/// assert_eq!(oblique_p02_p01_strong(5.0_f64, 1.4_f64, 0.3490659_f64), 0.06280201149490013);
+/// ```
+pub fn oblique_p02_p01_strong<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
+    let beta = oblique_beta_strong(mach, gamma, theta);
+    p02_p01_beta(mach, gamma, beta)
+}
+
+/// Static pressure ratio across strong oblique shock
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_p2_p1_strong;
+///
+/// assert_eq!(oblique_p2_p1_strong(2.0_f32, 1.4_f32, 0.1745329_f32), 4.443807);
+/// assert_eq!(oblique_p2_p1_strong(5.0_f64, 1.4_f64, 0.3490659_f64), 28.73749955496267);
+/// ```
+pub fn oblique_p2_p1_strong<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
+    let beta = oblique_beta_strong(mach, gamma, theta);
+    p2_p1_beta(mach, gamma, beta)
+}
+
+/// Static density ratio across strong oblique shock
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_rho2_rho1_strong;
+///
+/// assert_eq!(oblique_rho2_rho1_strong(2.0_f32, 1.4_f32, 0.1745329_f32), 2.648732);
+/// assert_eq!(oblique_rho2_rho1_strong(5.0_f64, 1.4_f64, 0.3490659_f64), 4.992443312028779);
+/// ```
+pub fn oblique_rho2_rho1_strong<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
+    let beta = oblique_beta_strong(mach, gamma, theta);
+    rho2_rho1_beta(mach, gamma, beta)
+}
+
+/// Static temperature ratio across strong oblique shock
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_t2_t1_strong;
+///
+/// assert_eq!(oblique_t2_t1_strong(2.0_f32, 1.4_f32, 0.1745329_f32), 1.6777112);
+/// assert_eq!(oblique_t2_t1_strong(5.0_f64, 1.4_f64, 0.3490659_f64), 5.756199471654013);
+/// ```
+pub fn oblique_t2_t1_strong<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
+    let beta = oblique_beta_strong(mach, gamma, theta);
+    t2_t1_beta(mach, gamma, beta)
+}
+
+/// Speed of sound ratio across strong oblique shock
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::oblique_a2_a1_strong;
+///
+/// assert_eq!(oblique_a2_a1_strong(2.0_f32, 1.4_f32, 0.1745329_f32), 1.295265);
+/// assert_eq!(oblique_a2_a1_strong(5.0_f64, 1.4_f64, 0.3490659_f64), 2.3992080926118127);
+/// ```
+pub fn oblique_a2_a1_strong<F: Float>(mach: F, gamma: F, theta: F) -> F {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    validate::deflection(theta);
+    let beta = oblique_beta_strong(mach, gamma, theta);
+    a2_a1_beta(mach, gamma, beta)
+}
+
+/// Oblique shock solved once for all downstream ratios
+///
+/// Every `oblique_*` function solves for the wave angle internally. When several
+/// ratios are needed for the same shock, `ObliqueShock` solves for it once and
+/// evaluates the ratios from the stored wave angle.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{oblique_p2_p1, ObliqueShock, ShockDetached};
+///
+/// let shock = ObliqueShock::new(2.0_f64, 1.4, 0.1745329).unwrap();
+/// assert_eq!(shock.beta(), 0.6861575251551221);
+/// assert_eq!(shock.p2_p1(), oblique_p2_p1(2.0, 1.4, 0.1745329));
+///
+/// assert_eq!(ObliqueShock::new(2.0_f64, 1.4, 0.5), Err(ShockDetached));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObliqueShock<F> {
+    mach: F,
+    gamma: F,
+    theta: F,
+    beta: F,
+}
+
+impl<F: Float> ObliqueShock<F> {
+    /// Weak oblique shock for a given upstream Mach number and deflection.
+    pub fn new(mach: F, gamma: F, theta: F) -> Result<Self, ShockDetached> {
+        let beta = oblique_beta_checked(mach, gamma, theta)?;
+        Ok(Self {
+            mach,
+            gamma,
+            theta,
+            beta,
+        })
+    }
+
+    /// Weak oblique shock in a gas at upstream static temperature `t1`
+    ///
+    /// The specific heat ratio is taken at `t1` and held constant across the
+    /// shock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::{gases, ObliqueShock};
+    ///
+    /// let theta = 10_f64.to_radians();
+    /// let shock = ObliqueShock::from_gas(&gases::air(), 2.0, 300.0, theta)?;
+    /// assert_eq!(shock, ObliqueShock::new(2.0, 1.4, theta)?);
+    /// # Ok::<(), comp_flow::ShockDetached>(())
+    /// ```
+    pub fn from_gas<G: Gas<F>>(gas: &G, mach: F, t1: F, theta: F) -> Result<Self, ShockDetached> {
+        Self::new(mach, gas.gamma(t1), theta)
+    }
+
+    /// Strong oblique shock for a given upstream Mach number and deflection.
+    pub fn strong(mach: F, gamma: F, theta: F) -> Result<Self, ShockDetached> {
+        let beta = oblique_beta_strong_checked(mach, gamma, theta)?;
+        Ok(Self {
+            mach,
+            gamma,
+            theta,
+            beta,
+        })
+    }
+
+    /// Oblique shock for a given upstream Mach number and wave angle.
+    pub fn from_beta(mach: F, gamma: F, beta: F) -> Self {
+        Self {
+            mach,
+            gamma,
+            theta: oblique_theta(mach, gamma, beta),
+            beta,
+        }
+    }
+
+    /// Upstream Mach number.
+    pub fn mach(&self) -> F {
+        self.mach
+    }
+
+    /// Specific heat ratio.
+    pub fn gamma(&self) -> F {
+        self.gamma
+    }
+
+    /// Flow deflection angle in radians.
+    pub fn theta(&self) -> F {
+        self.theta
+    }
+
+    /// Wave angle in radians.
+    pub fn beta(&self) -> F {
+        self.beta
+    }
+
+    /// Mach number after the shock.
+    pub fn mach2(&self) -> F {
+        mach2_beta(self.mach, self.gamma, self.beta)
+    }
+
+    /// Stagnation pressure ratio across the shock.
+    pub fn p02_p01(&self) -> F {
+        p02_p01_beta(self.mach, self.gamma, self.beta)
+    }
+
+    /// Static pressure ratio across the shock.
+    pub fn p2_p1(&self) -> F {
+        p2_p1_beta(self.mach, self.gamma, self.beta)
+    }
+
+    /// Static density ratio across the shock.
+    pub fn rho2_rho1(&self) -> F {
+        rho2_rho1_beta(self.mach, self.gamma, self.beta)
+    }
+
+    /// Static temperature ratio across the shock.
+    pub fn t2_t1(&self) -> F {
+        t2_t1_beta(self.mach, self.gamma, self.beta)
+    }
+
+    /// Speed of sound ratio across the shock.
+    pub fn a2_a1(&self) -> F {
+        a2_a1_beta(self.mach, self.gamma, self.beta)
+    }
+
+    /// Entropy rise (s2 - s1)/R across the shock.
+    pub fn ds_r(&self) -> F {
+        -self.p02_p01().ln()
+    }
+
+    /// Total pressure loss coefficient (p01 - p02)/(p01 - p1).
+    pub fn loss_coefficient(&self) -> F {
+        loss_coefficient(self.mach, self.gamma, self.p02_p01())
+    }
+
+    /// Every property of the shock, labelled with its registered quantity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::ObliqueShock;
+    ///
+    /// let shock = ObliqueShock::new(2.0_f64, 1.4, 0.1745329).unwrap();
+    /// let (quantity, value) = shock.quantities()[3];
+    /// assert_eq!(quantity.symbol, "beta");
+    /// assert_eq!(value, 0.6861575251551221);
+    /// ```
+    pub fn quantities(&self) -> Vec<(&'static Quantity, F)> {
+        vec![
+            (&Quantity::MACH, self.mach),
+            (&Quantity::GAMMA, self.gamma),
+            (&Quantity::THETA, self.theta()),
+            (&Quantity::BETA, self.beta()),
+            (&Quantity::MACH2, self.mach2()),
+            (&Quantity::P2_P1, self.p2_p1()),
+            (&Quantity::RHO2_RHO1, self.rho2_rho1()),
+            (&Quantity::T2_T1, self.t2_t1()),
+            (&Quantity::A2_A1, self.a2_a1()),
+            (&Quantity::P02_P01, self.p02_p01()),
+            (&Quantity::DS_R, self.ds_r()),
+            (&Quantity::LOSS_COEFFICIENT, self.loss_coefficient()),
+        ]
+    }
+}
+
+/// Density, velocity and static pressure after a stationary oblique shock
+/// with a given unit normal
+///
+/// The velocity component along `normal` jumps as across a normal shock and
+/// the tangential component is unchanged. The normal may point either way.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{oblique_p2_p1, oblique_shock_primitive, ObliqueShock};
+///
+/// let shock = ObliqueShock::new(2.0_f64, 1.4, 0.1745329).unwrap();
+/// let a1 = 1.4_f64.sqrt();
+/// let normal = [shock.beta().sin(), -shock.beta().cos()];
+/// let (_, u2, p2) = oblique_shock_primitive(1.0, [2.0 * a1, 0.0], 1.0, normal, 1.4);
+/// assert!((p2 - shock.p2_p1()).abs() < 1e-12);
+/// assert!((u2[1].atan2(u2[0]) - 0.1745329).abs() < 1e-9);
+/// ```
+pub fn oblique_shock_primitive<F: Float>(
+    rho1: F,
+    u1: [F; 2],
+    p1: F,
+    normal: [F; 2],
+    gamma: F,
+) -> (F, [F; 2], F) {
+    let un1 = u1[0] * normal[0] + u1[1] * normal[1];
+    let (rho2, un2, p2) = normal_shock_primitive(rho1, un1, p1, gamma);
+    let du = un2 - un1;
+    (rho2, [u1[0] + du * normal[0], u1[1] + du * normal[1]], p2)
+}
+
+/// Shock properties implied by measured upstream and downstream velocities
+///
+/// Returned by [`oblique_from_velocities`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImpliedShock<F> {
+    /// Wave angle between the upstream velocity and the shock.
+    pub beta: F,
+    /// Flow deflection between the upstream and downstream velocities.
+    pub theta: F,
+    /// Deflection minus the deflection the theta-beta-mach relation predicts
+    /// for `beta`; close to zero for velocities consistent with an oblique shock.
+    pub residual: F,
+}
+
+/// Wave angle and deflection implied by 2-D velocity vectors either side of a shock
+///
+/// The tangential velocity is unchanged across a shock, so the shock lies
+/// perpendicular to the velocity jump `v2 - v1`. Both angles are returned as
+/// magnitudes, whichever way the flow turns. Useful for extracting shock
+/// properties from CFD or PIV velocity fields.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{oblique_beta, oblique_from_velocities, oblique_rho2_rho1};
+///
+/// let (mach, gamma, theta) = (2.0_f64, 1.4_f64, 0.1745329_f64);
+/// let beta = oblique_beta(mach, gamma, theta);
+/// let rho2_rho1 = oblique_rho2_rho1(mach, gamma, theta);
+///
+/// // Rebuild the downstream velocity from its tangential and normal components.
+/// let (vt, vn) = (beta.cos(), beta.sin() / rho2_rho1);
+/// let v2 = [vt * beta.cos() + vn * beta.sin(), vt * beta.sin() - vn * beta.cos()];
+///
+/// let shock = oblique_from_velocities([1.0, 0.0], v2, mach, gamma);
+/// assert!((shock.beta - beta).abs() < 1e-12);
+/// assert!((shock.theta - theta).abs() < 1e-6);
+/// assert!(shock.residual.abs() < 1e-6);
+/// ```
+pub fn oblique_from_velocities<F: Float>(
+    v1: [F; 2],
+    v2: [F; 2],
+    mach: F,
+    gamma: F,
+) -> ImpliedShock<F> {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let jump = [v2[0] - v1[0], v2[1] - v1[1]];
+    let jump_mag = jump[0].hypot(jump[1]);
+    let v1_mag = v1[0].hypot(v1[1]);
+
+    let v1n = -(v1[0] * jump[0] + v1[1] * jump[1]) / jump_mag;
+    let beta = (v1n / v1_mag).asin().abs();
+    let theta = (v1[0] * v2[1] - v1[1] * v2[0])
+        .atan2(v1[0] * v2[0] + v1[1] * v2[1])
+        .abs();
+
+    ImpliedShock {
+        beta,
+        theta,
+        residual: theta - oblique_theta(mach, gamma, beta),
+    }
+}
+
+/// Regular reflection of an oblique shock from a wall
+///
+/// Returned by [`oblique_reflection`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObliqueReflection<F> {
+    /// Wave angle of the incident shock relative to the upstream flow.
+    pub beta_incident: F,
+    /// Wave angle of the reflected shock relative to the flow it meets.
+    pub beta_reflected: F,
+    /// Angle between the reflected shock and the wall.
+    pub reflected_wall_angle: F,
+    /// Mach number between the shocks.
+    pub mach2: F,
+    /// Mach number after the reflected shock.
+    pub mach3: F,
+    /// Static pressure between the shocks relative to upstream.
+    pub p2_p1: F,
+    /// Static pressure after the reflected shock relative to upstream.
+    pub p3_p1: F,
+    /// Stagnation pressure after the reflected shock relative to upstream.
+    pub p03_p01: F,
+}
+
+/// Regular reflection of an oblique shock from a flat wall
+///
+/// The incident shock turns a stream flowing parallel to the wall through
+/// `theta` towards it, and the reflected shock turns it back through `theta`
+/// to run parallel to the wall again. Both shocks are weak. Returns
+/// [`ShockDetached`] if either shock cannot stay attached, in which case a
+/// Mach reflection forms instead.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{oblique_reflection, ShockDetached};
+///
+/// let theta = 10_f64.to_radians();
+/// let r = oblique_reflection(2.5_f64, 1.4, theta)?;
+/// assert_eq!(r.mach3, 1.7196733778490718);
+/// assert_eq!(r.p3_p1, 3.2248495542902753);
+/// assert_eq!(r.reflected_wall_angle, r.beta_reflected - theta);
+///
+/// // The reflected shock detaches before the incident one does.
+/// assert_eq!(oblique_reflection(2.0_f64, 1.4, 0.25), Err(ShockDetached));
+/// # Ok::<(), ShockDetached>(())
+/// ```
+pub fn oblique_reflection<F: Float>(
+    mach1: F,
+    gamma: F,
+    theta: F,
+) -> Result<ObliqueReflection<F>, ShockDetached> {
+    let incident = ObliqueShock::new(mach1, gamma, theta)?;
+    if incident.mach2() <= F::one() {
+        return Err(ShockDetached);
+    }
+    let reflected = ObliqueShock::new(incident.mach2(), gamma, theta)?;
+    Ok(ObliqueReflection {
+        beta_incident: incident.beta(),
+        beta_reflected: reflected.beta(),
+        reflected_wall_angle: reflected.beta() - theta,
+        mach2: incident.mach2(),
+        mach3: reflected.mach2(),
+        p2_p1: incident.p2_p1(),
+        p3_p1: incident.p2_p1() * reflected.p2_p1(),
+        p03_p01: incident.p02_p01() * reflected.p02_p01(),
+    })
+}
+
+/// Checks that an attached oblique shock exists for the given deflection.
+fn attached<F: Float>(mach: F, gamma: F, theta: F) -> Result<(), ShockDetached> {
+    if theta > oblique_theta_max(mach, gamma) {
+        Err(ShockDetached)
+    } else {
+        Ok(())
+    }
+}
+
+/// Residual of the theta-beta-mach relation, zero at a shock solution.
+fn theta_beta_residual<F: Float>(mach: F, gamma: F, theta: F) -> impl Fn(F) -> F + Copy {
+    let two = F::from(2.0).unwrap();
+    move |x: F| {
+        theta.tan()
+            - two / x.tan() * (mach.powi(2) * x.sin().powi(2) - F::one())
+                / (mach.powi(2) * (gamma + (two * x).cos()) + two)
+    }
+}
+
+fn mach2_beta<F: Float>(mach: F, gamma: F, beta: F) -> F {
+    let two = F::from(2.).unwrap();
+
+    ((F::one() + (gamma - F::one()) / two * mach.powi(2))
+        / (gamma * mach.powi(2) * beta.sin().powi(2) - (gamma - F::one()) / two)
+        + (mach.powi(2) * beta.cos().powi(2))
+            / (F::one() + (gamma - F::one()) / two * mach.powi(2) * beta.sin().powi(2)))
+    .sqrt()
+}
+
+fn p02_p01_beta<F: Float>(mach: F, gamma: F, beta: F) -> F {
+    let mach1n = mach * beta.sin();
+    let two = F::from(2.).unwrap();
+
+    F::one()
+        / ((two * gamma / (gamma + F::one()) * mach1n.powi(2)
+            - (gamma - F::one()) / (gamma + F::one()))
+        .powf(F::one() / (gamma - F::one()))
+            * (two / (gamma + F::one()) / mach1n.powi(2) + (gamma - F::one()) / (gamma + F::one()))
+                .powf(gamma / (gamma - F::one())))
+}
+
+fn p2_p1_beta<F: Float>(mach: F, gamma: F, beta: F) -> F {
+    let mach1n = mach * beta.sin();
+    F::from(2.).unwrap() * gamma / (gamma + F::one()) * (mach1n.powi(2) - F::one()) + F::one()
+}
+
+fn rho2_rho1_beta<F: Float>(mach: F, gamma: F, beta: F) -> F {
+    let mach1n = mach * beta.sin();
+    (gamma + F::one()) * mach1n.powi(2)
+        / ((gamma - F::one()) * mach1n.powi(2) + F::from(2.).unwrap())
+}
+
+fn t2_t1_beta<F: Float>(mach: F, gamma: F, beta: F) -> F {
+    let two = F::from(2.).unwrap();
+    let mach1n = mach * beta.sin();
+    (two + (gamma - F::one()) * mach1n.powi(2))
+        * (two * gamma * mach1n.powi(2) - (gamma - F::one()))
+        / ((gamma + F::one()).powi(2) * mach1n.powi(2))
+}
+
+fn a2_a1_beta<F: Float>(mach: F, gamma: F, beta: F) -> F {
+    let two = F::from(2.).unwrap();
     let mach1n = mach * beta.sin();
     ((two + (gamma - F::one()) * mach1n.powi(2))
         * (two * gamma * mach1n.powi(2) - (gamma - F::one()))
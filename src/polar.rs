@@ -0,0 +1,296 @@
+//! Shock polars
+//!
+//! A shock polar is the locus of all states reachable from one upstream state
+//! through an oblique shock of any strength, traced by sweeping the wave
+//! angle from the Mach angle to a normal shock. Plotted as pressure ratio
+//! against deflection, or as the downstream velocity in the hodograph plane,
+//! it is the standard construction for shock reflections and interactions.
+//!
+//! [`ShockPolar`] places a polar at the pressure and flow direction of a
+//! state in a wave pattern, and [`polar_intersections`] finds where two such
+//! polars cross: the common pressure and direction either side of a slip line
+//! behind crossing or reflected shocks.
+use crate::{
+    mach_from_normal_p2_p1, normal_p2_p1, oblique_beta_max, oblique_theta, validate, ObliqueShock,
+    ShockDetached,
+};
+use num::Float;
+use std::cmp::Ordering;
+
+/// One state on a shock polar
+///
+/// Returned by [`shock_polar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShockPolarPoint<F> {
+    /// Wave angle in radians.
+    pub beta: F,
+    /// Flow deflection in radians.
+    pub theta: F,
+    /// Static pressure ratio across the shock.
+    pub p2_p1: F,
+    /// Downstream velocity component along the upstream flow, over the
+    /// upstream speed.
+    pub vx: F,
+    /// Downstream velocity component normal to the upstream flow, over the
+    /// upstream speed.
+    pub vy: F,
+    /// Whether the point lies on the strong branch, past the maximum
+    /// deflection.
+    pub strong: bool,
+}
+
+/// Points on the shock polar for an upstream Mach number
+///
+/// Only the upper half, with positive deflection, is returned; the lower half
+/// is its mirror image. The `n` points are evenly spaced in wave angle from the Mach angle, a
+/// vanishing wave with `p2_p1` of one, to a normal shock with zero deflection,
+/// passing through the weak branch and then the strong one.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{normal_p2_p1, shock_polar};
+///
+/// let polar = shock_polar(2.0_f64, 1.4, 5);
+/// assert_eq!(polar[0].p2_p1, 1.0);
+/// assert_eq!((polar[2].theta, polar[2].strong), (0.3911441771362581, false));
+/// assert_eq!(polar[4].p2_p1, normal_p2_p1(2.0, 1.4));
+/// assert!(polar[3].strong);
+///
+/// // The hodograph point lies at the deflection angle.
+/// let p = polar[2];
+/// assert!((p.vy.atan2(p.vx) - p.theta).abs() < 1e-12);
+/// ```
+pub fn shock_polar<F: Float>(mach: F, gamma: F, n: usize) -> Vec<ShockPolarPoint<F>> {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let mu = mach.recip().asin();
+    let half_pi = F::from(std::f64::consts::FRAC_PI_2).unwrap();
+    let beta_max = oblique_beta_max(mach, gamma);
+    let steps = F::from(n.max(2) - 1).unwrap();
+    (0..n)
+        .map(|i| {
+            let beta = mu + (half_pi - mu) * F::from(i).unwrap() / steps;
+            let shock = ObliqueShock::from_beta(mach, gamma, beta);
+            // The normal velocity drops by the density ratio and the
+            // tangential velocity is unchanged.
+            let drop = beta.sin() * (F::one() - shock.rho2_rho1().recip());
+            ShockPolarPoint {
+                beta,
+                theta: shock.theta(),
+                p2_p1: shock.p2_p1(),
+                vx: F::one() - beta.sin() * drop,
+                vy: beta.cos() * drop,
+                strong: beta > beta_max,
+            }
+        })
+        .collect()
+}
+
+/// Shock polar of a state in a wave pattern
+///
+/// The state has a Mach number, a static pressure relative to some common
+/// reference, and a flow direction in radians relative to a common axis.
+/// Pressures and directions reached through shocks from it are in the same
+/// reference and axis, so polars from different states can be intersected.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{oblique_p2_p1, ShockPolar};
+///
+/// // Regular reflection of a 10 degree wedge shock from a flat wall.
+/// let theta = 10_f64.to_radians();
+/// let incident = ShockPolar::new(2.5_f64, 1.4).deflect(theta)?;
+/// assert_eq!(incident.p(), oblique_p2_p1(2.5, 1.4, theta));
+/// assert_eq!(incident.pressure(0.0)?, 3.2248495542902753);
+/// # Ok::<(), comp_flow::ShockDetached>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShockPolar<F> {
+    mach: F,
+    gamma: F,
+    p: F,
+    direction: F,
+}
+
+impl<F: Float> ShockPolar<F> {
+    /// Polar of a free stream at unit pressure flowing along the axis.
+    pub fn new(mach: F, gamma: F) -> Self {
+        Self::from_state(mach, gamma, F::one(), F::zero())
+    }
+
+    /// Polar of a state with a given pressure and flow direction.
+    pub fn from_state(mach: F, gamma: F, p: F, direction: F) -> Self {
+        validate::supersonic(mach);
+        validate::gamma(gamma);
+        Self {
+            mach,
+            gamma,
+            p,
+            direction,
+        }
+    }
+
+    /// Mach number.
+    pub fn mach(&self) -> F {
+        self.mach
+    }
+
+    /// Specific heat ratio.
+    pub fn gamma(&self) -> F {
+        self.gamma
+    }
+
+    /// Static pressure relative to the common reference.
+    pub fn p(&self) -> F {
+        self.p
+    }
+
+    /// Flow direction in radians.
+    pub fn direction(&self) -> F {
+        self.direction
+    }
+
+    /// Static pressure after a weak shock turning the flow to the absolute
+    /// direction `theta`.
+    pub fn pressure(&self, theta: F) -> Result<F, ShockDetached> {
+        let shock = ObliqueShock::new(self.mach, self.gamma, (theta - self.direction).abs())?;
+        Ok(self.p * shock.p2_p1())
+    }
+
+    /// Polar of the state after a weak shock turning the flow to the absolute
+    /// direction `theta`.
+    pub fn deflect(&self, theta: F) -> Result<Self, ShockDetached> {
+        let shock = ObliqueShock::new(self.mach, self.gamma, (theta - self.direction).abs())?;
+        Ok(Self::from_state(
+            shock.mach2(),
+            self.gamma,
+            self.p * shock.p2_p1(),
+            theta,
+        ))
+    }
+
+    /// Highest pressure on the polar, behind a normal shock.
+    fn p_max(&self) -> F {
+        self.p * normal_p2_p1(self.mach, self.gamma)
+    }
+
+    /// Wave angle of the shock reaching pressure `p`.
+    fn beta(&self, p: F) -> F {
+        let p2_p1 = (p / self.p).max(F::one());
+        let mach_n = mach_from_normal_p2_p1(p2_p1, self.gamma);
+        (mach_n / self.mach).min(F::one()).asin()
+    }
+
+    /// Absolute flow direction at pressure `p` on the upper or lower half.
+    fn direction_at(&self, p: F, upper: bool) -> F {
+        let theta = oblique_theta(self.mach, self.gamma, self.beta(p));
+        if upper {
+            self.direction + theta
+        } else {
+            self.direction - theta
+        }
+    }
+}
+
+/// Crossing of two shock polars
+///
+/// Returned by [`polar_intersections`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolarIntersection<F> {
+    /// Common flow direction in radians.
+    pub theta: F,
+    /// Common static pressure.
+    pub p: F,
+    /// Whether the crossing lies on the strong branch of the first polar.
+    pub strong_a: bool,
+    /// Whether the crossing lies on the strong branch of the second polar.
+    pub strong_b: bool,
+}
+
+/// All crossings of two shock polars, in order of increasing pressure
+///
+/// Pressure rises monotonically with wave angle along each half of a polar,
+/// so each half is a function of pressure and the crossings are the sign
+/// changes of the difference in flow direction over the common pressure range.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{polar_intersections, ShockPolar};
+///
+/// // Shocks from wedges of 10 and 6 degrees on opposite walls cross, and
+/// // the transmitted shocks turn both streams to a common direction.
+/// let free = ShockPolar::new(3.0_f64, 1.4);
+/// let lower = free.deflect(10_f64.to_radians())?;
+/// let upper = free.deflect(-6_f64.to_radians())?;
+/// let slip = polar_intersections(&lower, &upper)
+///     .into_iter()
+///     .find(|x| !x.strong_a && !x.strong_b)
+///     .unwrap();
+/// assert_eq!(slip.theta.to_degrees(), 3.979502650196072);
+/// assert_eq!(slip.p, 3.02141223220036);
+/// assert!((lower.pressure(slip.theta)? - slip.p).abs() < 1e-9);
+/// assert!((upper.pressure(slip.theta)? - slip.p).abs() < 1e-9);
+/// # Ok::<(), comp_flow::ShockDetached>(())
+/// ```
+pub fn polar_intersections<F: Float>(
+    a: &ShockPolar<F>,
+    b: &ShockPolar<F>,
+) -> Vec<PolarIntersection<F>> {
+    let samples = 200;
+    let lo = a.p.max(b.p);
+    let hi = a.p_max().min(b.p_max());
+    let mut crossings: Vec<PolarIntersection<F>> = Vec::new();
+    if hi.partial_cmp(&lo) != Some(Ordering::Greater) {
+        return crossings;
+    }
+    let tol = F::epsilon().sqrt();
+    for (upper_a, upper_b) in [(true, true), (true, false), (false, true), (false, false)] {
+        let gap = |p: F| a.direction_at(p, upper_a) - b.direction_at(p, upper_b);
+        let at = |i: usize| lo + (hi - lo) * F::from(i).unwrap() / F::from(samples).unwrap();
+        for i in 0..samples {
+            let (mut p0, mut p1) = (at(i), at(i + 1));
+            let (mut g0, g1) = (gap(p0), gap(p1));
+            if g0 == F::zero() && i > 0 {
+                // Counted as the end of the previous interval.
+                continue;
+            }
+            if g0.signum() == g1.signum() && g1 != F::zero() {
+                continue;
+            }
+            for _ in 0..100 {
+                let mid = (p0 + p1) / F::from(2.).unwrap();
+                let g = gap(mid);
+                if g.signum() == g0.signum() && g != F::zero() {
+                    p0 = mid;
+                    g0 = g;
+                } else {
+                    p1 = mid;
+                }
+                if p1 - p0 <= F::epsilon() * p1 {
+                    break;
+                }
+            }
+            let p = (p0 + p1) / F::from(2.).unwrap();
+            let theta = a.direction_at(p, upper_a);
+            // The two halves of a polar meet at the origin and at the normal
+            // shock, where the same crossing can be found twice.
+            if crossings
+                .iter()
+                .any(|c| (c.p - p).abs() <= tol * p && (c.theta - theta).abs() <= tol)
+            {
+                continue;
+            }
+            crossings.push(PolarIntersection {
+                theta,
+                p,
+                strong_a: a.beta(p) > oblique_beta_max(a.mach, a.gamma),
+                strong_b: b.beta(p) > oblique_beta_max(b.mach, b.gamma),
+            });
+        }
+    }
+    crossings.sort_by(|x, y| x.p.partial_cmp(&y.p).unwrap_or(Ordering::Equal));
+    crossings
+}
@@ -0,0 +1,211 @@
+//! Names, symbols and units for the quantities the crate computes
+//!
+//! Every ratio and angle reported by the state structs has one entry here,
+//! keyed by the name of the accessor that returns it. Reports and tables can
+//! label columns from the registry instead of repeating strings, and a
+//! quantity typed by a user can be resolved with [`Quantity::by_name`].
+
+/// Description of a computed quantity
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::Quantity;
+///
+/// let q = Quantity::by_name("p/p0").unwrap();
+/// assert_eq!(q, &Quantity::P_P0);
+/// assert_eq!(q.name, "p_p0");
+/// assert_eq!(q.units, "-");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Quantity {
+    /// Identifier, matching the accessor that returns the quantity.
+    pub name: &'static str,
+    /// Conventional symbol in plain text.
+    pub symbol: &'static str,
+    /// One-line definition.
+    pub definition: &'static str,
+    /// Units, `-` for dimensionless quantities.
+    pub units: &'static str,
+}
+
+const fn quantity(
+    name: &'static str,
+    symbol: &'static str,
+    definition: &'static str,
+    units: &'static str,
+) -> Quantity {
+    Quantity {
+        name,
+        symbol,
+        definition,
+        units,
+    }
+}
+
+impl Quantity {
+    /// Mach number.
+    pub const MACH: Self = quantity("mach", "M", "Mach number", "-");
+    /// Specific heat ratio.
+    pub const GAMMA: Self = quantity("gamma", "gamma", "Specific heat ratio cp/cv", "-");
+    /// Static to stagnation temperature ratio.
+    pub const T_T0: Self = quantity(
+        "t_t0",
+        "T/T0",
+        "Static to stagnation temperature ratio",
+        "-",
+    );
+    /// Static to stagnation pressure ratio.
+    pub const P_P0: Self = quantity("p_p0", "p/p0", "Static to stagnation pressure ratio", "-");
+    /// Static to stagnation density ratio.
+    pub const RHO_RHO0: Self = quantity(
+        "rho_rho0",
+        "rho/rho0",
+        "Static to stagnation density ratio",
+        "-",
+    );
+    /// Critical area ratio.
+    pub const A_AC: Self = quantity("a_ac", "A/A*", "Area relative to the sonic throat", "-");
+    /// Normalised velocity.
+    pub const V_CPT0: Self = quantity(
+        "v_cpt0",
+        "V/sqrt(cp T0)",
+        "Velocity normalised by stagnation enthalpy",
+        "-",
+    );
+    /// Mass flow function based on stagnation pressure.
+    pub const MCPT0_AP0: Self = quantity(
+        "mcpt0_ap0",
+        "m sqrt(cp T0)/(A p0)",
+        "Mass flow function based on stagnation pressure",
+        "-",
+    );
+    /// Mass flow function based on static pressure.
+    pub const MCPT0_AP: Self = quantity(
+        "mcpt0_ap",
+        "m sqrt(cp T0)/(A p)",
+        "Mass flow function based on static pressure",
+        "-",
+    );
+    /// Prandtl-Meyer angle.
+    pub const PM_ANGLE: Self = quantity("pm_angle", "nu", "Prandtl-Meyer angle", "rad");
+    /// Mach angle.
+    pub const MACH_ANGLE: Self = quantity("mach_angle", "mu", "Mach angle", "rad");
+    /// Flow deflection angle.
+    pub const THETA: Self = quantity("theta", "theta", "Flow deflection angle", "rad");
+    /// Shock wave angle.
+    pub const BETA: Self = quantity(
+        "beta",
+        "beta",
+        "Wave angle between the upstream flow and the shock",
+        "rad",
+    );
+    /// Mach number after a shock.
+    pub const MACH2: Self = quantity("mach2", "M2", "Mach number after the shock", "-");
+    /// Static pressure ratio across a shock.
+    pub const P2_P1: Self = quantity(
+        "p2_p1",
+        "p2/p1",
+        "Static pressure ratio across the shock",
+        "-",
+    );
+    /// Density ratio across a shock.
+    pub const RHO2_RHO1: Self = quantity(
+        "rho2_rho1",
+        "rho2/rho1",
+        "Density ratio across the shock",
+        "-",
+    );
+    /// Static temperature ratio across a shock.
+    pub const T2_T1: Self = quantity(
+        "t2_t1",
+        "T2/T1",
+        "Static temperature ratio across the shock",
+        "-",
+    );
+    /// Speed of sound ratio across a shock.
+    pub const A2_A1: Self = quantity(
+        "a2_a1",
+        "a2/a1",
+        "Speed of sound ratio across the shock",
+        "-",
+    );
+    /// Stagnation pressure ratio across a shock.
+    pub const P02_P01: Self = quantity(
+        "p02_p01",
+        "p02/p01",
+        "Stagnation pressure ratio across the shock",
+        "-",
+    );
+    /// Pitot pressure ratio.
+    pub const P02_P1: Self = quantity(
+        "p02_p1",
+        "p02/p1",
+        "Stagnation pressure after the shock to upstream static pressure",
+        "-",
+    );
+    /// Entropy rise across a shock.
+    pub const DS_R: Self = quantity(
+        "ds_r",
+        "(s2 - s1)/R",
+        "Entropy rise across the shock over the gas constant",
+        "-",
+    );
+    /// Stagnation pressure loss coefficient.
+    pub const LOSS_COEFFICIENT: Self = quantity(
+        "loss_coefficient",
+        "Y",
+        "Stagnation pressure loss coefficient (p01 - p02)/(p01 - p1)",
+        "-",
+    );
+    /// Stagnation pressure relative to the start of a flow path.
+    pub const P0_P0_INLET: Self = quantity(
+        "p0_p0_inlet",
+        "p0/p0_inlet",
+        "Stagnation pressure relative to the start of the flow path",
+        "-",
+    );
+    /// Static pressure relative to the stagnation pressure at the start of a
+    /// flow path.
+    pub const P_P0_INLET: Self = quantity(
+        "p_p0_inlet",
+        "p/p0_inlet",
+        "Static pressure relative to the stagnation pressure at the start of the flow path",
+        "-",
+    );
+
+    /// Registered quantity with a given name or symbol.
+    pub fn by_name(name: &str) -> Option<&'static Self> {
+        QUANTITIES
+            .iter()
+            .find(|q| q.name == name || q.symbol == name)
+    }
+}
+
+/// Every registered quantity
+pub const QUANTITIES: &[Quantity] = &[
+    Quantity::MACH,
+    Quantity::GAMMA,
+    Quantity::T_T0,
+    Quantity::P_P0,
+    Quantity::RHO_RHO0,
+    Quantity::A_AC,
+    Quantity::V_CPT0,
+    Quantity::MCPT0_AP0,
+    Quantity::MCPT0_AP,
+    Quantity::PM_ANGLE,
+    Quantity::MACH_ANGLE,
+    Quantity::THETA,
+    Quantity::BETA,
+    Quantity::MACH2,
+    Quantity::P2_P1,
+    Quantity::RHO2_RHO1,
+    Quantity::T2_T1,
+    Quantity::A2_A1,
+    Quantity::P02_P01,
+    Quantity::P02_P1,
+    Quantity::DS_R,
+    Quantity::LOSS_COEFFICIENT,
+    Quantity::P0_P0_INLET,
+    Quantity::P_P0_INLET,
+];
@@ -0,0 +1,245 @@
+//! Multi-ramp external compression
+//!
+//! An external-compression inlet turns the captured stream through a series
+//! of ramps, each producing a weak oblique shock. Spreading the turn over
+//! several weaker shocks recovers more stagnation pressure than a single
+//! strong one, which is the reason for the extra ramps. [`oswatitsch_inlet`]
+//! chooses the ramps for the best recovery with a terminal normal shock.
+use crate::{normal_p02_p01, validate, ObliqueShock, ShockDetached};
+use num::Float;
+
+/// One shock in a multi-ramp compression
+///
+/// Ratios marked cumulative are relative to the free stream ahead of the
+/// first ramp.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RampShock<F> {
+    /// Ramp deflection in radians.
+    pub theta: F,
+    /// Wave angle relative to the flow ahead of the shock.
+    pub beta: F,
+    /// Mach number ahead of the shock.
+    pub mach_in: F,
+    /// Mach number behind the shock.
+    pub mach_out: F,
+    /// Static pressure ratio across the shock.
+    pub p2_p1: F,
+    /// Stagnation pressure ratio across the shock.
+    pub p02_p01: F,
+    /// Cumulative static pressure ratio behind the shock.
+    pub p_p1: F,
+    /// Cumulative stagnation pressure ratio behind the shock.
+    pub p0_p01: F,
+}
+
+/// Conditions through a sequence of compression ramps
+///
+/// Returned by [`ramp_compression`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RampCompression<F> {
+    /// Free-stream Mach number.
+    pub mach: F,
+    /// Shocks in order from the first ramp.
+    pub shocks: Vec<RampShock<F>>,
+}
+
+impl<F: Float> RampCompression<F> {
+    /// Mach number behind the last shock.
+    pub fn mach_out(&self) -> F {
+        self.shocks.last().map_or(self.mach, |s| s.mach_out)
+    }
+
+    /// Static pressure behind the last shock relative to the free stream.
+    pub fn p_p1(&self) -> F {
+        self.shocks.last().map_or(F::one(), |s| s.p_p1)
+    }
+
+    /// Stagnation pressure behind the last shock relative to the free stream.
+    pub fn p0_p01(&self) -> F {
+        self.shocks.last().map_or(F::one(), |s| s.p0_p01)
+    }
+
+    /// Total turning of the flow in radians.
+    pub fn theta(&self) -> F {
+        self.shocks.iter().fold(F::zero(), |sum, s| sum + s.theta)
+    }
+
+    /// Angle of each shock to the free stream in radians, which fixes where
+    /// the shocks meet the cowl.
+    pub fn shock_angles(&self) -> Vec<F> {
+        let mut turned = F::zero();
+        self.shocks
+            .iter()
+            .map(|s| {
+                let angle = turned + s.beta;
+                turned = turned + s.theta;
+                angle
+            })
+            .collect()
+    }
+
+    fn push(&mut self, shock: ObliqueShock<F>) {
+        let (p_p1, p0_p01) = (self.p_p1(), self.p0_p01());
+        self.shocks.push(RampShock {
+            theta: shock.theta(),
+            beta: shock.beta(),
+            mach_in: shock.mach(),
+            mach_out: shock.mach2(),
+            p2_p1: shock.p2_p1(),
+            p02_p01: shock.p02_p01(),
+            p_p1: p_p1 * shock.p2_p1(),
+            p0_p01: p0_p01 * shock.p02_p01(),
+        });
+    }
+}
+
+/// Weak oblique shocks from a sequence of ramp deflections
+///
+/// Each entry of `thetas` is the additional deflection of one ramp in
+/// radians. Returns [`ShockDetached`] if any shock would detach, including
+/// when a shock leaves subsonic flow ahead of the next ramp.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{oblique_p02_p01, ramp_compression, ShockDetached};
+///
+/// // Two 8 degree ramps against one 16 degree ramp at Mach 3.
+/// let ramps = ramp_compression(3.0_f64, 1.4, &[8_f64.to_radians(), 8_f64.to_radians()])?;
+/// assert_eq!(ramps.mach_out(), 2.259529796199498);
+/// assert_eq!(ramps.p_p1(), 3.0231579996759343);
+/// assert_eq!(ramps.p0_p01(), 0.9659647255874447);
+/// assert!(ramps.p0_p01() > oblique_p02_p01(3.0, 1.4, 16_f64.to_radians()));
+///
+/// assert_eq!(
+///     ramp_compression(3.0_f64, 1.4, &[0.4, 0.4]),
+///     Err(ShockDetached)
+/// );
+/// # Ok::<(), ShockDetached>(())
+/// ```
+pub fn ramp_compression<F: Float>(
+    mach: F,
+    gamma: F,
+    thetas: &[F],
+) -> Result<RampCompression<F>, ShockDetached> {
+    validate::supersonic(mach);
+    validate::gamma(gamma);
+    let mut ramps = RampCompression {
+        mach,
+        shocks: Vec::with_capacity(thetas.len()),
+    };
+    for &theta in thetas {
+        if ramps.mach_out() <= F::one() {
+            return Err(ShockDetached);
+        }
+        ramps.push(ObliqueShock::new(ramps.mach_out(), gamma, theta)?);
+    }
+    Ok(ramps)
+}
+
+/// Multi-shock inlet designed by the Oswatitsch criterion
+///
+/// Returned by [`oswatitsch_inlet`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OswatitschInlet<F> {
+    /// Normal Mach number shared by every shock.
+    pub normal_mach: F,
+    /// Oblique shocks from the ramps.
+    pub ramps: RampCompression<F>,
+    /// Mach number ahead of the terminal normal shock.
+    pub terminal_mach: F,
+    /// Stagnation pressure recovery including the terminal normal shock.
+    pub recovery: F,
+}
+
+/// Ramp angles for the best stagnation pressure recovery from `n` oblique
+/// shocks and a terminal normal shock
+///
+/// Oswatitsch showed that recovery is highest when every shock has the same
+/// normal Mach number. This uses the usual equal-strength form of the
+/// criterion, in which the terminal normal shock also has that Mach number,
+/// and solves for it by bisection. The ramp deflections are
+/// `ramps.shocks[i].theta` and the shock positions follow from
+/// [`RampCompression::shock_angles`].
+///
+/// Subsonic and sonic flow needs no shocks, and gives an inlet with no ramps
+/// and a recovery of one.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{normal_p02_p01, oswatitsch_inlet};
+///
+/// let inlet = oswatitsch_inlet(3.0_f64, 1.4, 2);
+/// assert_eq!(inlet.normal_mach, 1.5721475975984458);
+/// assert_eq!(inlet.recovery, 0.7421570568365217);
+/// assert!((inlet.terminal_mach - inlet.normal_mach).abs() < 1e-12);
+///
+/// // More shocks recover more, and a lone normal shock the least.
+/// assert!(oswatitsch_inlet(3.0_f64, 1.4, 3).recovery > inlet.recovery);
+/// assert_eq!(oswatitsch_inlet(3.0_f64, 1.4, 0).recovery, normal_p02_p01(3.0, 1.4));
+///
+/// let sonic = oswatitsch_inlet(1.0_f64, 1.4, 2);
+/// assert_eq!(sonic.recovery, 1.0);
+/// assert!(sonic.ramps.shocks.is_empty());
+/// ```
+pub fn oswatitsch_inlet<F: Float>(mach: F, gamma: F, n: usize) -> OswatitschInlet<F> {
+    validate::gamma(gamma);
+    let unshocked = |recovery: F| OswatitschInlet {
+        normal_mach: mach,
+        ramps: RampCompression {
+            mach,
+            shocks: Vec::new(),
+        },
+        terminal_mach: mach,
+        recovery,
+    };
+    if mach <= F::one() {
+        return unshocked(F::one());
+    }
+    // Stronger shocks leave a lower terminal Mach number, so the excess of
+    // the terminal over the shared normal Mach number falls as it rises.
+    let chain = |normal_mach: F| {
+        let mut ramps = RampCompression {
+            mach,
+            shocks: Vec::with_capacity(n),
+        };
+        for _ in 0..n {
+            // A normal Mach number above the local one has no shock.
+            if normal_mach >= ramps.mach_out() {
+                return None;
+            }
+            let beta = (normal_mach / ramps.mach_out()).asin();
+            ramps.push(ObliqueShock::from_beta(ramps.mach_out(), gamma, beta));
+        }
+        Some(ramps)
+    };
+    let excess = |normal_mach: F| {
+        chain(normal_mach).map_or(-F::one(), |ramps| ramps.mach_out() - normal_mach)
+    };
+    let (mut lo, mut hi) = (F::one(), mach);
+    for _ in 0..200 {
+        let mid = (lo + hi) / F::from(2.).unwrap();
+        if excess(mid) > F::zero() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+        if hi - lo <= F::epsilon() * hi {
+            break;
+        }
+    }
+    // The lower bound has a complete chain unless rounding leaves a Mach
+    // wave subsonic just above Mach one.
+    let normal_mach = lo;
+    let Some(ramps) = chain(normal_mach) else {
+        return unshocked(F::nan());
+    };
+    let terminal_mach = ramps.mach_out();
+    OswatitschInlet {
+        normal_mach,
+        recovery: ramps.p0_p01() * normal_p02_p01(terminal_mach, gamma),
+        ramps,
+        terminal_mach,
+    }
+}
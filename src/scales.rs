@@ -0,0 +1,101 @@
+//! Reference scales for non-dimensional solvers
+use crate::{Gas, PrimitiveState};
+use num::Float;
+
+/// Consistent set of reference scales
+///
+/// Velocities are scaled by `velocity`, densities by `density` and lengths by
+/// `length`. Pressure and energy per unit volume are scaled by
+/// `density * velocity^2` and time by `length / velocity`, so the Euler
+/// equations keep their dimensional form. Temperature has its own scale,
+/// which does not enter the other quantities.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{CaloricallyPerfect, PrimitiveState, ReferenceScales};
+///
+/// let air = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// let scales = ReferenceScales::from_freestream(&air, 0.5, 101325.0, 288.15);
+///
+/// // Freestream pressure becomes 1/gamma.
+/// let state = PrimitiveState::new(scales.density, 340.0, 101325.0);
+/// let nd = scales.to_nondimensional(state);
+/// assert!((nd.p - 1.0 / 1.4).abs() < 1e-12);
+/// assert!((scales.to_dimensional(nd).u - 340.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReferenceScales<F> {
+    /// Reference length.
+    pub length: F,
+    /// Reference velocity.
+    pub velocity: F,
+    /// Reference density.
+    pub density: F,
+    /// Reference temperature.
+    pub temperature: F,
+}
+
+impl<F: Float> ReferenceScales<F> {
+    /// Scales from a reference length, velocity, density and temperature.
+    pub fn new(length: F, velocity: F, density: F, temperature: F) -> Self {
+        Self {
+            length,
+            velocity,
+            density,
+            temperature,
+        }
+    }
+
+    /// Scales based on a freestream static state, with the speed of sound as
+    /// the reference velocity.
+    pub fn from_freestream<G: Gas<F>>(gas: &G, length: F, p: F, t: F) -> Self {
+        Self::new(length, gas.speed_of_sound(t), p / (gas.r() * t), t)
+    }
+
+    /// Reference pressure, also the scale of energy per unit volume.
+    pub fn pressure(&self) -> F {
+        self.density * self.velocity.powi(2)
+    }
+
+    /// Reference time.
+    pub fn time(&self) -> F {
+        self.length / self.velocity
+    }
+
+    /// Non-dimensional primitive state.
+    pub fn to_nondimensional(&self, state: PrimitiveState<F>) -> PrimitiveState<F> {
+        PrimitiveState::new(
+            state.rho / self.density,
+            state.u / self.velocity,
+            state.p / self.pressure(),
+        )
+    }
+
+    /// Dimensional primitive state.
+    pub fn to_dimensional(&self, state: PrimitiveState<F>) -> PrimitiveState<F> {
+        PrimitiveState::new(
+            state.rho * self.density,
+            state.u * self.velocity,
+            state.p * self.pressure(),
+        )
+    }
+
+    /// Non-dimensional conservative variables `[rho, rho u, E]`.
+    pub fn conservative_to_nondimensional(&self, [rho, m, e]: [F; 3]) -> [F; 3] {
+        [
+            rho / self.density,
+            m / (self.density * self.velocity),
+            e / self.pressure(),
+        ]
+    }
+
+    /// Dimensional conservative variables `[rho, rho u, E]`.
+    pub fn conservative_to_dimensional(&self, [rho, m, e]: [F; 3]) -> [F; 3] {
+        [
+            rho * self.density,
+            m * self.density * self.velocity,
+            e * self.pressure(),
+        ]
+    }
+}
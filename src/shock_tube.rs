@@ -0,0 +1,116 @@
+//! Shock tube wave interactions
+//!
+//! Velocities are made non-dimensional with the speed of sound of the gas
+//! ahead of the incident shock, and pressures with its static pressure.
+use crate::{normal_a2_a1, normal_p2_p1, validate};
+use eqsolver::single_variable::FDNewton;
+use num::Float;
+
+/// Shock transmission through a contact surface between two gases
+///
+/// An incident shock in gas 1 reaches an interface with gas 2, both initially
+/// at rest and at the same pressure. A shock is transmitted into gas 2 and a
+/// wave is reflected back into the shocked gas 1: a shock when gas 2 has the
+/// higher acoustic impedance, an expansion otherwise. The strengths follow from
+/// matching pressure and velocity across the interface after the interaction.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::ShockInterface;
+///
+/// // Identical gases: the shock passes straight through.
+/// let same = ShockInterface::new(2.0_f64, 1.4, 1.4, 1.0);
+/// assert!((same.transmitted_mach() - 2.0).abs() < 1e-9);
+/// assert!((same.reflected_p_ratio() - 1.0).abs() < 1e-9);
+///
+/// // Helium driving into air reflects a shock.
+/// let he_air = ShockInterface::new(2.0_f64, 5.0 / 3.0, 1.4, 0.34);
+/// assert!(he_air.reflected_shock());
+/// assert_eq!(he_air.transmitted_mach(), 2.652038815520339);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShockInterface<F> {
+    mach: F,
+    transmitted_mach: F,
+    p_contact_p1: F,
+    reflected_p_ratio: F,
+    u_contact_a1: F,
+}
+
+impl<F: Float> ShockInterface<F> {
+    /// Interaction of a shock of Mach number `mach` in gas 1 with gas 2,
+    /// where `a2_a1` is the ratio of the initial speeds of sound.
+    pub fn new(mach: F, gamma1: F, gamma2: F, a2_a1: F) -> Self {
+        validate::supersonic(mach);
+        validate::gamma(gamma1);
+        validate::gamma(gamma2);
+        // State behind the incident shock, in the lab frame.
+        let p2 = normal_p2_p1(mach, gamma1);
+        let a2 = normal_a2_a1(mach, gamma1);
+        let u2 = F::from(2.).unwrap() / (gamma1 + F::one()) * (mach - F::one() / mach);
+
+        let mismatch = |p3: F| {
+            wave_velocity(p3, F::one(), a2_a1, gamma2) - (u2 - wave_velocity(p3, p2, a2, gamma1))
+        };
+        let p3 = FDNewton::new(mismatch).solve(p2).unwrap_or(F::nan());
+
+        Self {
+            mach,
+            transmitted_mach: (F::one()
+                + (gamma2 + F::one()) / (F::from(2.).unwrap() * gamma2) * (p3 - F::one()))
+            .sqrt(),
+            p_contact_p1: p3,
+            reflected_p_ratio: p3 / p2,
+            u_contact_a1: wave_velocity(p3, F::one(), a2_a1, gamma2),
+        }
+    }
+
+    /// Mach number of the incident shock.
+    pub fn mach(&self) -> F {
+        self.mach
+    }
+
+    /// Mach number of the shock transmitted into gas 2.
+    pub fn transmitted_mach(&self) -> F {
+        self.transmitted_mach
+    }
+
+    /// Pressure at the interface after the interaction, relative to the
+    /// initial pressure.
+    pub fn p_contact_p1(&self) -> F {
+        self.p_contact_p1
+    }
+
+    /// Pressure ratio across the reflected wave; greater than one for a
+    /// reflected shock and less than one for a reflected expansion.
+    pub fn reflected_p_ratio(&self) -> F {
+        self.reflected_p_ratio
+    }
+
+    /// Whether the reflected wave is a shock.
+    pub fn reflected_shock(&self) -> bool {
+        self.reflected_p_ratio > F::one()
+    }
+
+    /// Velocity of the interface after the interaction, relative to the
+    /// initial speed of sound of gas 1.
+    pub fn u_contact_a1(&self) -> F {
+        self.u_contact_a1
+    }
+}
+
+/// Velocity jump across a wave raising the pressure from `p_k` to `p`, in a
+/// gas with speed of sound `a_k`; negative for an expansion.
+fn wave_velocity<F: Float>(p: F, p_k: F, a_k: F, gamma: F) -> F {
+    let two = F::from(2.).unwrap();
+    let ratio = p / p_k;
+    if ratio > F::one() {
+        a_k / gamma
+            * (ratio - F::one())
+            * (two * gamma / (gamma + F::one()) / (ratio + (gamma - F::one()) / (gamma + F::one())))
+                .sqrt()
+    } else {
+        two * a_k / (gamma - F::one()) * (ratio.powf((gamma - F::one()) / (two * gamma)) - F::one())
+    }
+}
@@ -0,0 +1,60 @@
+//! Sonic reference conditions
+//!
+//! Ratios of the conditions at M = 1, marked with a star, to the stagnation
+//! conditions. They depend only on the specific heat ratio and are the usual
+//! reference state for nozzle, Fanno and Rayleigh flow.
+use crate::validate;
+use num::Float;
+
+/// Sonic temperature ratio T*/T0 for a given specific heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::sonic_t_t0;
+///
+/// assert_eq!(sonic_t_t0(1.4_f64), 0.8333333333333334);
+/// ```
+pub fn sonic_t_t0<F: Float>(gamma: F) -> F {
+    validate::gamma(gamma);
+    F::from(2.).unwrap() / (gamma + F::one())
+}
+
+/// Sonic pressure ratio p*/p0 for a given specific heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::sonic_p_p0;
+///
+/// assert_eq!(sonic_p_p0(1.4_f64), 0.5282817877171742);
+/// ```
+pub fn sonic_p_p0<F: Float>(gamma: F) -> F {
+    sonic_t_t0(gamma).powf(gamma / (gamma - F::one()))
+}
+
+/// Sonic density ratio rho*/rho0 for a given specific heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::sonic_rho_rho0;
+///
+/// assert_eq!(sonic_rho_rho0(1.4_f64), 0.633938145260609);
+/// ```
+pub fn sonic_rho_rho0<F: Float>(gamma: F) -> F {
+    sonic_t_t0(gamma).powf(F::one() / (gamma - F::one()))
+}
+
+/// Sonic speed of sound ratio a*/a0 for a given specific heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::sonic_a_a0;
+///
+/// assert_eq!(sonic_a_a0(1.4_f64), 0.9128709291752769);
+/// ```
+pub fn sonic_a_a0<F: Float>(gamma: F) -> F {
+    sonic_t_t0(gamma).sqrt()
+}
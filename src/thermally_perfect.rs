@@ -0,0 +1,320 @@
+//! Thermally perfect gas relations
+//!
+//! When cp varies with temperature the closed-form constant-gamma relations
+//! no longer hold. The functions here instead solve the conservation equations
+//! iteratively for any [`Gas`], and reduce to the usual results for a
+//! [`CaloricallyPerfect`](crate::CaloricallyPerfect) gas. In air the
+//! difference becomes noticeable above roughly Mach 3 to 4, where vibrational
+//! excitation of the molecules absorbs part of the energy.
+//!
+//! Two temperature-dependent models are provided: [`HarmonicOscillator`] for
+//! a diatomic gas with a single vibrational mode, and [`NasaPolynomial`] for
+//! species described by NASA thermodynamic polynomials.
+use crate::{validate, Gas};
+use eqsolver::single_variable::FDNewton;
+use num::Float;
+
+/// Diatomic gas with a harmonic-oscillator vibrational mode
+///
+/// Translational and rotational modes are fully excited and contribute a
+/// constant cp, set by the frozen specific heat ratio; the vibrational mode
+/// adds a temperature-dependent contribution with characteristic temperature
+/// `theta_v`. Dissociation is not modelled. For air, `theta_v` is about
+/// 3056 K with a frozen `gamma` of 1.4.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{Gas, HarmonicOscillator};
+///
+/// let air = HarmonicOscillator::new(1.4_f64, 287.0, 3056.0);
+/// assert!((air.gamma(300.0) - 1.4).abs() < 1e-3);
+/// assert!(air.gamma(2000.0) < 1.31);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HarmonicOscillator<F> {
+    /// Specific heat ratio with the vibrational mode frozen.
+    pub gamma_frozen: F,
+    /// Specific gas constant.
+    pub r: F,
+    /// Characteristic vibrational temperature.
+    pub theta_v: F,
+}
+
+impl<F: Float> HarmonicOscillator<F> {
+    /// Gas with a given frozen specific heat ratio, gas constant and
+    /// characteristic vibrational temperature.
+    pub fn new(gamma_frozen: F, r: F, theta_v: F) -> Self {
+        Self {
+            gamma_frozen,
+            r,
+            theta_v,
+        }
+    }
+
+    fn cp_frozen(&self) -> F {
+        self.gamma_frozen * self.r / (self.gamma_frozen - F::one())
+    }
+}
+
+impl<F: Float> Gas<F> for HarmonicOscillator<F> {
+    fn r(&self) -> F {
+        self.r
+    }
+
+    fn cp(&self, t: F) -> F {
+        let x = self.theta_v / t;
+        self.cp_frozen() + self.r * x.powi(2) * x.exp() / x.exp_m1().powi(2)
+    }
+
+    fn h(&self, t: F) -> F {
+        self.cp_frozen() * t + self.r * self.theta_v / (self.theta_v / t).exp_m1()
+    }
+
+    fn s0(&self, t: F) -> F {
+        let x = self.theta_v / t;
+        self.cp_frozen() * t.ln() + self.r * (x / x.exp_m1() - (-(-x).exp_m1()).ln())
+    }
+}
+
+/// Gas with cp(T) from NASA 7-coefficient polynomials
+///
+/// Each temperature range has coefficients `a1..a7` such that
+///
+/// cp/R = a1 + a2 T + a3 T^2 + a4 T^3 + a5 T^4
+///
+/// with `a6` and `a7` the enthalpy and entropy integration constants. `low`
+/// applies below `t_mid` and `high` above it. The specific gas constant is the
+/// universal gas constant divided by the molar mass of the species.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{Gas, NasaPolynomial};
+///
+/// // Nitrogen, GRI-Mech 3.0 data.
+/// let n2 = NasaPolynomial::new(
+///     8.314462618_f64 / 0.0280134,
+///     1000.0,
+///     [3.298677, 1.4082404e-3, -3.963222e-6, 5.641515e-9, -2.444854e-12, -1020.8999, 3.950372],
+///     [2.92664, 1.4879768e-3, -5.68476e-7, 1.0097038e-10, -6.753351e-15, -922.7977, 5.980528],
+/// );
+/// assert_eq!(n2.gamma(300.0), 1.4004843092635317);
+/// assert_eq!(n2.gamma(2000.0), 1.3004447461945896);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NasaPolynomial<F> {
+    /// Specific gas constant.
+    pub r: F,
+    /// Temperature separating the low and high ranges.
+    pub t_mid: F,
+    /// Coefficients below `t_mid`.
+    pub low: [F; 7],
+    /// Coefficients above `t_mid`.
+    pub high: [F; 7],
+}
+
+impl<F: Float> NasaPolynomial<F> {
+    /// Gas with a given specific gas constant and polynomial coefficients.
+    pub fn new(r: F, t_mid: F, low: [F; 7], high: [F; 7]) -> Self {
+        Self {
+            r,
+            t_mid,
+            low,
+            high,
+        }
+    }
+
+    fn coefficients(&self, t: F) -> &[F; 7] {
+        if t < self.t_mid {
+            &self.low
+        } else {
+            &self.high
+        }
+    }
+}
+
+impl<F: Float> Gas<F> for NasaPolynomial<F> {
+    fn r(&self) -> F {
+        self.r
+    }
+
+    fn cp(&self, t: F) -> F {
+        let a = self.coefficients(t);
+        self.r * (a[0] + t * (a[1] + t * (a[2] + t * (a[3] + t * a[4]))))
+    }
+
+    fn h(&self, t: F) -> F {
+        let a = self.coefficients(t);
+        let n = |k: f64| F::from(k).unwrap();
+        let poly =
+            a[0] + t * (a[1] / n(2.) + t * (a[2] / n(3.) + t * (a[3] / n(4.) + t * a[4] / n(5.))));
+        self.r * (poly * t + a[5])
+    }
+
+    fn s0(&self, t: F) -> F {
+        let a = self.coefficients(t);
+        let n = |k: f64| F::from(k).unwrap();
+        let poly = a[1] + t * (a[2] / n(2.) + t * (a[3] / n(3.) + t * a[4] / n(4.)));
+        self.r * (a[0] * t.ln() + poly * t + a[6])
+    }
+}
+
+/// Temperature at which a gas has a given specific enthalpy
+///
+/// Solved with Newton's method starting from `t_guess`.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{temperature_from_enthalpy, Gas, HarmonicOscillator};
+///
+/// let air = HarmonicOscillator::new(1.4_f64, 287.0, 3056.0);
+/// let t = temperature_from_enthalpy(&air, air.h(1500.0), 1000.0);
+/// assert!((t - 1500.0).abs() < 1e-9);
+/// ```
+pub fn temperature_from_enthalpy<F: Float, G: Gas<F>>(gas: &G, h: F, t_guess: F) -> F {
+    let f = |t: F| gas.h(t) - h;
+    FDNewton::new(f)
+        .with_tol(t_guess * F::epsilon().sqrt())
+        .solve(t_guess)
+        .unwrap_or(F::nan())
+}
+
+/// Stagnation temperature for a given Mach number and static temperature
+///
+/// Solves h(T0) = h(T) + V^2 / 2 with V = M a(T).
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{mach_to_t_t0, thermally_perfect_t0, CaloricallyPerfect, HarmonicOscillator};
+///
+/// let perfect = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// let t0 = thermally_perfect_t0(&perfect, 5.0, 220.0);
+/// assert!((t0 - 220.0 / mach_to_t_t0(5.0, 1.4)).abs() < 1e-6);
+///
+/// // Vibrational excitation absorbs energy and lowers T0.
+/// let air = HarmonicOscillator::new(1.4_f64, 287.0, 3056.0);
+/// assert!(thermally_perfect_t0(&air, 5.0, 220.0) < t0 - 50.0);
+/// ```
+pub fn thermally_perfect_t0<F: Float, G: Gas<F>>(gas: &G, mach: F, t: F) -> F {
+    validate::mach(mach);
+    let half = F::from(0.5).unwrap();
+    let velocity = mach * gas.speed_of_sound(t);
+    let h0 = gas.h(t) + half * velocity.powi(2);
+    temperature_from_enthalpy(gas, h0, t * (F::one() + half * mach.powi(2)))
+}
+
+/// Static to stagnation pressure ratio for a given Mach number and static
+/// temperature
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{mach_to_p_p0, thermally_perfect_p_p0, CaloricallyPerfect};
+///
+/// let perfect = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// let p_p0 = thermally_perfect_p_p0(&perfect, 2.0, 288.0);
+/// assert!((p_p0 - mach_to_p_p0(2.0, 1.4)).abs() < 1e-9);
+/// ```
+pub fn thermally_perfect_p_p0<F: Float, G: Gas<F>>(gas: &G, mach: F, t: F) -> F {
+    let t0 = thermally_perfect_t0(gas, mach, t);
+    ((gas.s0(t) - gas.s0(t0)) / gas.r()).exp()
+}
+
+/// Normal shock in a thermally perfect gas
+///
+/// The jump ratios depend on the upstream temperature as well as the Mach
+/// number. They are found by iterating on the density ratio until mass,
+/// momentum and energy are conserved.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{normal_p2_p1, normal_t2_t1, CaloricallyPerfect, HarmonicOscillator, ThermallyPerfectNormalShock};
+///
+/// let perfect = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// let shock = ThermallyPerfectNormalShock::new(&perfect, 3.0, 300.0);
+/// assert!((shock.p2_p1() - normal_p2_p1(3.0, 1.4)).abs() < 1e-6);
+///
+/// let air = HarmonicOscillator::new(1.4_f64, 287.0, 3056.0);
+/// let shock = ThermallyPerfectNormalShock::new(&air, 8.0, 220.0);
+///
+/// // Constant-gamma theory overpredicts the post-shock temperature.
+/// assert!(shock.t2_t1() < 0.9 * normal_t2_t1(8.0, 1.4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThermallyPerfectNormalShock<F> {
+    mach2: F,
+    p2_p1: F,
+    rho2_rho1: F,
+    t2_t1: F,
+    p02_p01: F,
+}
+
+impl<F: Float> ThermallyPerfectNormalShock<F> {
+    /// Normal shock for a given upstream Mach number and static temperature.
+    pub fn new<G: Gas<F>>(gas: &G, mach: F, t1: F) -> Self {
+        validate::supersonic(mach);
+        let half = F::from(0.5).unwrap();
+        let r = gas.r();
+        let u1 = mach * gas.speed_of_sound(t1);
+        let h1 = gas.h(t1);
+        // Per unit upstream pressure, so rho1 = 1 / (R T1).
+        let rho1 = F::one() / (r * t1);
+        let tol = F::epsilon().sqrt();
+
+        let mut eps = F::from(0.1).unwrap();
+        let mut t2 = t1;
+        for _ in 0..100 {
+            let p2 = F::one() + rho1 * u1.powi(2) * (F::one() - eps);
+            let h2 = h1 + half * u1.powi(2) * (F::one() - eps.powi(2));
+            t2 = temperature_from_enthalpy(gas, h2, t2);
+            let eps_new = rho1 * r * t2 / p2;
+            let converged = (eps_new - eps).abs() < tol * eps;
+            eps = eps_new;
+            if converged {
+                break;
+            }
+        }
+
+        let p2_p1 = F::one() + rho1 * u1.powi(2) * (F::one() - eps);
+        let mach2 = eps * u1 / gas.speed_of_sound(t2);
+        // Stagnation temperature is conserved, so only the entropy rise
+        // changes the stagnation pressure.
+        let ds_r = (gas.s0(t2) - gas.s0(t1)) / r - p2_p1.ln();
+        Self {
+            mach2,
+            p2_p1,
+            rho2_rho1: F::one() / eps,
+            t2_t1: t2 / t1,
+            p02_p01: (-ds_r).exp(),
+        }
+    }
+
+    /// Mach number after the shock.
+    pub fn mach2(&self) -> F {
+        self.mach2
+    }
+
+    /// Static pressure ratio across the shock.
+    pub fn p2_p1(&self) -> F {
+        self.p2_p1
+    }
+
+    /// Static density ratio across the shock.
+    pub fn rho2_rho1(&self) -> F {
+        self.rho2_rho1
+    }
+
+    /// Static temperature ratio across the shock.
+    pub fn t2_t1(&self) -> F {
+        self.t2_t1
+    }
+
+    /// Stagnation pressure ratio across the shock.
+    pub fn p02_p01(&self) -> F {
+        self.p02_p01
+    }
+}
@@ -0,0 +1,66 @@
+//! Flow conditions along a flight trajectory
+use crate::atmosphere::R_AIR;
+use crate::{
+    dynamic_pressure, probe_stagnation_point, standard_atmosphere, sutton_graves_heat_flux,
+    validate, CaloricallyPerfect,
+};
+use num::Float;
+
+/// Flow conditions at one point of a trajectory
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrajectoryPoint<F> {
+    /// Geopotential altitude.
+    pub altitude: F,
+    /// Flight velocity.
+    pub velocity: F,
+    /// Flight Mach number.
+    pub mach: F,
+    /// Freestream dynamic pressure.
+    pub dynamic_pressure: F,
+    /// Stagnation pressure at the nose, behind the bow shock when supersonic.
+    pub p0: F,
+    /// Stagnation temperature.
+    pub t0: F,
+    /// Sutton-Graves stagnation point heat flux.
+    pub heat_flux: F,
+}
+
+/// Flow conditions along a trajectory given as `(altitude, velocity)` pairs
+///
+/// The freestream follows the standard atmosphere and air is treated as a
+/// perfect gas with gamma = 1.4, so stagnation temperatures at entry speeds
+/// are overestimated. Heat flux is for a nose of radius `nose_radius`.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::trajectory;
+///
+/// let points = [(60000.0_f64, 6000.0), (40000.0, 3000.0), (20000.0, 600.0)];
+/// let table = trajectory(&points, 0.5);
+/// assert_eq!(table[0].mach, 19.10402015523612);
+/// assert_eq!(table[1].heat_flux, 412656.45103928616);
+/// assert!(table[0].t0 > table[2].t0);
+/// ```
+pub fn trajectory<F: Float>(points: &[(F, F)], nose_radius: F) -> Vec<TrajectoryPoint<F>> {
+    let gamma = F::from(1.4).unwrap();
+    let air = CaloricallyPerfect::new(gamma, F::from(R_AIR).unwrap());
+    points
+        .iter()
+        .map(|&(altitude, velocity)| {
+            let state = standard_atmosphere(altitude);
+            let mach = velocity / state.a;
+            validate::mach(mach);
+            let stag = probe_stagnation_point(&air, mach, state.p, state.t);
+            TrajectoryPoint {
+                altitude,
+                velocity,
+                mach,
+                dynamic_pressure: dynamic_pressure(gamma, state.p, mach),
+                p0: stag.p,
+                t0: stag.t,
+                heat_flux: sutton_graves_heat_flux(state.rho, velocity, nose_radius),
+            }
+        })
+        .collect()
+}
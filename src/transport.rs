@@ -0,0 +1,117 @@
+//! Transport properties: viscosity, thermal conductivity and Prandtl number
+use crate::Gas;
+use num::Float;
+
+/// Dynamic viscosity as a function of temperature
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::Viscosity;
+///
+/// let sutherland = Viscosity::sutherland_air();
+/// assert_eq!(sutherland.mu(273.15_f64), 1.716e-5);
+/// assert_eq!(sutherland.mu(1000.0), 4.1520063611410934e-5);
+///
+/// let power = Viscosity::PowerLaw { mu_ref: 1.716e-5, t_ref: 273.15, exponent: 0.7 };
+/// assert_eq!(power.mu(1000.0), 4.2563412509277976e-5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Viscosity<F> {
+    /// Temperature-independent viscosity.
+    Constant(F),
+    /// Sutherland's law, mu = mu_ref (T/T_ref)^1.5 (T_ref + s) / (T + s).
+    Sutherland {
+        /// Viscosity at the reference temperature.
+        mu_ref: F,
+        /// Reference temperature.
+        t_ref: F,
+        /// Sutherland temperature.
+        s: F,
+    },
+    /// Power law, mu = mu_ref (T/T_ref)^exponent.
+    PowerLaw {
+        /// Viscosity at the reference temperature.
+        mu_ref: F,
+        /// Reference temperature.
+        t_ref: F,
+        /// Temperature exponent.
+        exponent: F,
+    },
+}
+
+impl<F: Float> Viscosity<F> {
+    /// Sutherland's law with the usual constants for air.
+    pub fn sutherland_air() -> Self {
+        Viscosity::Sutherland {
+            mu_ref: F::from(1.716e-5).unwrap(),
+            t_ref: F::from(273.15).unwrap(),
+            s: F::from(110.4).unwrap(),
+        }
+    }
+
+    /// Dynamic viscosity at temperature `t`.
+    pub fn mu(&self, t: F) -> F {
+        match *self {
+            Viscosity::Constant(mu) => mu,
+            Viscosity::Sutherland { mu_ref, t_ref, s } => {
+                mu_ref * (t / t_ref).powf(F::from(1.5).unwrap()) * (t_ref + s) / (t + s)
+            }
+            Viscosity::PowerLaw {
+                mu_ref,
+                t_ref,
+                exponent,
+            } => mu_ref * (t / t_ref).powf(exponent),
+        }
+    }
+}
+
+/// Viscosity law and Prandtl number of a gas
+///
+/// Thermal conductivity follows from the Prandtl number, which is taken as
+/// constant.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{CaloricallyPerfect, Transport};
+///
+/// let air = CaloricallyPerfect::new(1.4_f64, 287.0);
+/// let transport = Transport::air();
+/// assert_eq!(transport.conductivity(&air, 300.0), 0.02611581513137986);
+/// assert_eq!(transport.reynolds(1.225, 50.0, 1.0, 288.15), 3423130.9039248344);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transport<F> {
+    /// Viscosity law.
+    pub viscosity: Viscosity<F>,
+    /// Prandtl number.
+    pub prandtl: F,
+}
+
+impl<F: Float> Transport<F> {
+    /// Transport properties from a viscosity law and Prandtl number.
+    pub fn new(viscosity: Viscosity<F>, prandtl: F) -> Self {
+        Self { viscosity, prandtl }
+    }
+
+    /// Air with Sutherland's law and a Prandtl number of 0.71.
+    pub fn air() -> Self {
+        Self::new(Viscosity::sutherland_air(), F::from(0.71).unwrap())
+    }
+
+    /// Dynamic viscosity at temperature `t`.
+    pub fn mu(&self, t: F) -> F {
+        self.viscosity.mu(t)
+    }
+
+    /// Thermal conductivity at temperature `t`.
+    pub fn conductivity<G: Gas<F>>(&self, gas: &G, t: F) -> F {
+        self.mu(t) * gas.cp(t) / self.prandtl
+    }
+
+    /// Reynolds number for a density, velocity, length and temperature.
+    pub fn reynolds(&self, rho: F, velocity: F, length: F, t: F) -> F {
+        rho * velocity * length / self.mu(t)
+    }
+}
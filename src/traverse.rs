@@ -0,0 +1,363 @@
+//! Reduction of pressure and temperature traverse data
+//!
+//! A traverse is a set of probe measurements taken along a line across a
+//! stream, either across a planar duct or along a radius of an axisymmetric
+//! one. Each point gives pitot pressure, static pressure and total temperature,
+//! from which the local flow state is recovered with the isentropic and
+//! normal-shock relations and then integrated across the section.
+use crate::{mach_from_a_ac, mach_from_pitot, mach_to_p_p0, mach_to_t_t0, validate, Gas};
+use num::Float;
+
+/// Shape of the section a traverse is taken across
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraverseGeometry {
+    /// Positions are distances across a planar duct; integrals are per unit
+    /// span.
+    Planar,
+    /// Positions are radii measured from the axis of an axisymmetric duct.
+    Axisymmetric,
+}
+
+/// Local flow state at one traverse point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TraversePoint<F> {
+    /// Mach number.
+    pub mach: F,
+    /// Static pressure.
+    pub p: F,
+    /// Stagnation pressure, corrected for the probe's bow shock when supersonic.
+    pub p0: F,
+    /// Static temperature.
+    pub t: F,
+    /// Stagnation temperature.
+    pub t0: F,
+    /// Density.
+    pub rho: F,
+    /// Velocity.
+    pub velocity: F,
+}
+
+/// Method for averaging a non-uniform traverse into a 1-D state
+///
+/// The choice changes the averaged stagnation pressure, and with it inlet
+/// recovery and nozzle efficiency figures, by amounts that often matter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Averaging {
+    /// Area-weighted stagnation pressure and temperature.
+    Area,
+    /// Mass-weighted stagnation pressure and temperature.
+    Mass,
+    /// Mass-weighted stagnation temperature and work-averaged stagnation
+    /// pressure, which preserves the isentropic work the flow could deliver.
+    Work,
+    /// Mass-weighted stagnation temperature and the stagnation pressure that
+    /// preserves the mass-weighted entropy.
+    Entropy,
+    /// The state with the same area, mass flow, stream thrust and energy flux
+    /// as the traverse, also known as the impulse average.
+    StreamThrust,
+}
+
+/// Uniform 1-D state equivalent to a non-uniform traverse
+///
+/// Returned by [`Traverse::average`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AveragedState<F> {
+    /// Mach number that passes the traverse mass flow through its area at the
+    /// averaged stagnation state.
+    pub mach: F,
+    /// Averaged stagnation pressure.
+    pub p0: F,
+    /// Averaged stagnation temperature.
+    pub t0: F,
+    /// Static pressure.
+    pub p: F,
+    /// Static temperature.
+    pub t: F,
+}
+
+/// Reduced traverse of pitot, static and total temperature measurements
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{Traverse, TraverseGeometry};
+///
+/// // Uniform Mach 0.5 air flow across a 0.1 m radius pipe.
+/// let r = [0.0, 0.05, 0.1];
+/// let traverse = Traverse::new(
+///     TraverseGeometry::Axisymmetric,
+///     &r,
+///     &[120_193.0_f64; 3],
+///     &[101_325.0; 3],
+///     &[300.0; 3],
+///     1.4,
+///     287.0,
+/// );
+///
+/// assert!((traverse.points()[0].mach - 0.5).abs() < 1e-6);
+/// assert!((traverse.area() - std::f64::consts::PI * 0.01).abs() < 1e-15);
+/// assert!((traverse.mass_flow() - 6.5765).abs() < 1e-4);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Traverse<F> {
+    gamma: F,
+    r: F,
+    geometry: TraverseGeometry,
+    positions: Vec<F>,
+    points: Vec<TraversePoint<F>>,
+}
+
+impl<F: Float> Traverse<F> {
+    /// Reduce traverse measurements to local flow states
+    ///
+    /// `positions` must be sorted. `p_pitot` is the pressure measured by a
+    /// pitot probe; where it exceeds the sonic ratio to `p_static` the flow is
+    /// taken to be supersonic and the probe to sit behind a normal shock.
+    /// `r` is the specific gas constant.
+    pub fn new(
+        geometry: TraverseGeometry,
+        positions: &[F],
+        p_pitot: &[F],
+        p_static: &[F],
+        t0: &[F],
+        gamma: F,
+        r: F,
+    ) -> Self {
+        validate::gamma(gamma);
+        let points = p_pitot
+            .iter()
+            .zip(p_static)
+            .zip(t0)
+            .map(|((&p_pitot, &p), &t0)| {
+                let mach = mach_from_pitot(p_pitot / p, gamma);
+                let t = t0 * mach_to_t_t0(mach, gamma);
+                TraversePoint {
+                    mach,
+                    p,
+                    p0: p / mach_to_p_p0(mach, gamma),
+                    t,
+                    t0,
+                    rho: p / (r * t),
+                    velocity: mach * (gamma * r * t).sqrt(),
+                }
+            })
+            .collect();
+        Self {
+            gamma,
+            r,
+            geometry,
+            positions: positions.to_vec(),
+            points,
+        }
+    }
+
+    /// Reduce traverse measurements for a gas
+    ///
+    /// As [`Traverse::new`], with the gas constant from `gas` and the
+    /// specific heat ratio taken at the mean measured total temperature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::{gases, Traverse, TraverseGeometry};
+    ///
+    /// let (y, p_pitot, p_static, t0) = ([0.0, 1.0], [1.2e5_f64; 2], [1.0e5; 2], [300.0; 2]);
+    /// let traverse = Traverse::from_gas(TraverseGeometry::Planar, &y, &p_pitot, &p_static, &t0, &gases::air());
+    /// let expected = Traverse::new(TraverseGeometry::Planar, &y, &p_pitot, &p_static, &t0, 1.4, 287.05);
+    /// assert_eq!(traverse, expected);
+    /// ```
+    pub fn from_gas<G: Gas<F>>(
+        geometry: TraverseGeometry,
+        positions: &[F],
+        p_pitot: &[F],
+        p_static: &[F],
+        t0: &[F],
+        gas: &G,
+    ) -> Self {
+        let n = F::from(t0.len()).unwrap();
+        let t0_mean = t0.iter().fold(F::zero(), |sum, &t| sum + t) / n;
+        Self::new(
+            geometry,
+            positions,
+            p_pitot,
+            p_static,
+            t0,
+            gas.gamma(t0_mean),
+            gas.r(),
+        )
+    }
+
+    /// Local flow states at each traverse position.
+    pub fn points(&self) -> &[TraversePoint<F>] {
+        &self.points
+    }
+
+    /// Traverse positions.
+    pub fn positions(&self) -> &[F] {
+        &self.positions
+    }
+
+    /// Shape of the traversed section.
+    pub fn geometry(&self) -> TraverseGeometry {
+        self.geometry
+    }
+
+    /// Area of the traversed section, per unit span for planar traverses.
+    pub fn area(&self) -> F {
+        self.integrate(|_| F::one())
+    }
+
+    /// Mass flow rate through the traversed section.
+    pub fn mass_flow(&self) -> F {
+        self.integrate(|q| q.rho * q.velocity)
+    }
+
+    /// Momentum flux, the integral of rho V^2, through the traversed section.
+    pub fn momentum_flux(&self) -> F {
+        self.integrate(|q| q.rho * q.velocity.powi(2))
+    }
+
+    /// Stream thrust, the integral of p + rho V^2, through the traversed
+    /// section.
+    pub fn stream_thrust(&self) -> F {
+        self.integrate(|q| q.p + q.rho * q.velocity.powi(2))
+    }
+
+    /// Area-weighted average of a pointwise quantity.
+    pub fn area_average(&self, f: impl Fn(&TraversePoint<F>) -> F) -> F {
+        self.integrate(f) / self.area()
+    }
+
+    /// Mass-weighted average of a pointwise quantity.
+    pub fn mass_average(&self, f: impl Fn(&TraversePoint<F>) -> F) -> F {
+        self.integrate(|q| q.rho * q.velocity * f(q)) / self.mass_flow()
+    }
+
+    /// Equivalent uniform state using the given averaging method
+    ///
+    /// The stagnation pressure and temperature are averaged according to
+    /// `method`; the Mach number then follows from continuity, on the
+    /// supersonic branch if the mass-averaged Mach number exceeds one.
+    /// [`Averaging::StreamThrust`] instead solves directly for the uniform
+    /// state that conserves mass, momentum and energy, choosing the
+    /// supersonic or subsonic root the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::{Averaging, Traverse, TraverseGeometry};
+    ///
+    /// // Linear p0 profile across a planar duct.
+    /// let y = [0.0, 0.5, 1.0];
+    /// let p_static = [1.0e5_f64; 3];
+    /// let p_pitot = [1.10e5, 1.15e5, 1.20e5];
+    /// let traverse = Traverse::new(TraverseGeometry::Planar, &y, &p_pitot, &p_static, &[300.0; 3], 1.4, 287.0);
+    ///
+    /// let area = traverse.average(Averaging::Area);
+    /// let mass = traverse.average(Averaging::Mass);
+    /// assert!((area.p0 - 1.15e5).abs() < 1e-9);
+    /// assert!(mass.p0 > area.p0);
+    /// assert!(traverse.average(Averaging::Entropy).p0 < mass.p0);
+    ///
+    /// // The stream-thrust average conserves the momentum flux.
+    /// let impulse = traverse.average(Averaging::StreamThrust);
+    /// let rho = impulse.p / (287.0 * impulse.t);
+    /// let v = impulse.mach * (1.4 * 287.0 * impulse.t).sqrt();
+    /// assert!(((impulse.p + rho * v * v) - traverse.stream_thrust()).abs() < 1e-6);
+    ///
+    /// // The work average conserves the isentropic work down to any reference
+    /// // pressure, here with the total temperature varying as well.
+    /// let t0 = [280.0, 300.0, 330.0];
+    /// let traverse = Traverse::new(TraverseGeometry::Planar, &y, &p_pitot, &p_static, &t0, 1.4, 287.0);
+    /// let e = 0.4 / 1.4;
+    /// let work = |t0: f64, p0: f64| t0 * (1.0 - (1.0e5 / p0).powf(e));
+    /// let averaged = traverse.average(Averaging::Work);
+    /// let exact = traverse.mass_average(|q| work(q.t0, q.p0));
+    /// assert!((work(averaged.t0, averaged.p0) - exact).abs() < 1e-12 * exact);
+    /// ```
+    pub fn average(&self, method: Averaging) -> AveragedState<F> {
+        let gamma = self.gamma;
+        let exponent = (gamma - F::one()) / gamma;
+        let (p0, t0) = match method {
+            Averaging::Area => (self.area_average(|q| q.p0), self.area_average(|q| q.t0)),
+            Averaging::Mass => (self.mass_average(|q| q.p0), self.mass_average(|q| q.t0)),
+            Averaging::Work => {
+                let t0 = self.mass_average(|q| q.t0);
+                let work = self.mass_average(|q| q.t0 * q.p0.powf(-exponent)) / t0;
+                (work.powf(-F::one() / exponent), t0)
+            }
+            Averaging::Entropy => {
+                let t0 = self.mass_average(|q| q.t0);
+                let s_r = self.mass_average(|q| q.t0.ln() / exponent - q.p0.ln());
+                ((t0.ln() / exponent - s_r).exp(), t0)
+            }
+            Averaging::StreamThrust => return self.stream_thrust_average(),
+        };
+
+        let two = F::from(2.0).unwrap();
+        let choked_flow = p0
+            * (gamma / (self.r * t0)).sqrt()
+            * (two / (gamma + F::one())).powf((gamma + F::one()) / (two * (gamma - F::one())));
+        let a_ac = self.area() * choked_flow / self.mass_flow();
+        let supersonic = self.mass_average(|q| q.mach) > F::one();
+        let mach = mach_from_a_ac(a_ac, gamma, supersonic);
+        AveragedState {
+            mach,
+            p0,
+            t0,
+            p: p0 * mach_to_p_p0(mach, gamma),
+            t: t0 * mach_to_t_t0(mach, gamma),
+        }
+    }
+
+    /// Uniform state conserving mass, stream thrust and energy flux.
+    fn stream_thrust_average(&self) -> AveragedState<F> {
+        let gamma = self.gamma;
+        let two = F::from(2.0).unwrap();
+        let area = self.area();
+        let mass_flow = self.mass_flow();
+        let cp = gamma * self.r / (gamma - F::one());
+        let h0 = cp * self.mass_average(|q| q.t0);
+        let impulse = self.stream_thrust() / mass_flow;
+
+        // Eliminating p and T leaves a quadratic in the uniform velocity whose
+        // larger root is the supersonic solution.
+        let a = (gamma + F::one()) / (two * gamma);
+        let c = (gamma - F::one()) / gamma * h0;
+        let root = (impulse.powi(2) - F::from(4.0).unwrap() * a * c).sqrt();
+        let velocity = if self.mass_average(|q| q.mach) > F::one() {
+            (impulse + root) / (two * a)
+        } else {
+            (impulse - root) / (two * a)
+        };
+
+        let t = (h0 - velocity.powi(2) / two) / cp;
+        let mach = velocity / (gamma * self.r * t).sqrt();
+        let p = mass_flow * self.r * t / (velocity * area);
+        let t0 = h0 / cp;
+        AveragedState {
+            mach,
+            p0: p / mach_to_p_p0(mach, gamma),
+            t0,
+            p,
+            t,
+        }
+    }
+
+    /// Trapezoidal integral of a pointwise quantity over the section area.
+    pub fn integrate(&self, f: impl Fn(&TraversePoint<F>) -> F) -> F {
+        let half = F::from(0.5).unwrap();
+        let tau = F::from(std::f64::consts::TAU).unwrap();
+        let weight = |x: F| match self.geometry {
+            TraverseGeometry::Planar => F::one(),
+            TraverseGeometry::Axisymmetric => tau * x,
+        };
+        self.positions
+            .windows(2)
+            .zip(self.points.windows(2))
+            .fold(F::zero(), |sum, (x, q)| {
+                sum + half * (f(&q[0]) * weight(x[0]) + f(&q[1]) * weight(x[1])) * (x[1] - x[0])
+            })
+    }
+}
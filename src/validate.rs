@@ -0,0 +1,99 @@
+//! Input checks enabled by the `strict` feature.
+//!
+//! Every check compiles to nothing unless the crate is built with the `strict`
+//! feature and debug assertions are enabled.
+use num::Float;
+
+fn show<F: Float>(value: F) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+/// Specific heat ratio must be greater than one.
+pub(crate) fn gamma<F: Float>(gamma: F) {
+    if cfg!(feature = "strict") {
+        debug_assert!(
+            gamma > F::one(),
+            "gamma must be greater than 1, got {}",
+            show(gamma)
+        );
+    }
+}
+
+/// Mach number must be non-negative.
+pub(crate) fn mach<F: Float>(mach: F) {
+    if cfg!(feature = "strict") {
+        debug_assert!(
+            mach >= F::zero(),
+            "mach must be non-negative, got {}",
+            show(mach)
+        );
+    }
+}
+
+/// Mach number must be at least one, e.g. upstream of a shock.
+pub(crate) fn supersonic<F: Float>(mach: F) {
+    if cfg!(feature = "strict") {
+        debug_assert!(
+            mach >= F::one(),
+            "mach must be >= 1 for this relation, got {}",
+            show(mach)
+        );
+    }
+}
+
+/// Stagnation ratios such as T/T0 must lie in (0, 1].
+pub(crate) fn ratio<F: Float>(name: &str, value: F) {
+    if cfg!(feature = "strict") {
+        debug_assert!(
+            value > F::zero() && value <= F::one(),
+            "{} must be in (0, 1], got {}",
+            name,
+            show(value)
+        );
+    }
+}
+
+/// Critical area ratio must be at least one.
+pub(crate) fn area_ratio<F: Float>(a_ac: F) {
+    if cfg!(feature = "strict") {
+        debug_assert!(a_ac >= F::one(), "a_ac must be >= 1, got {}", show(a_ac));
+    }
+}
+
+/// Mach angle must lie in (0, pi/2].
+pub(crate) fn mach_angle<F: Float>(mach_angle: F) {
+    if cfg!(feature = "strict") {
+        let half_pi = F::from(std::f64::consts::FRAC_PI_2).unwrap();
+        debug_assert!(
+            mach_angle > F::zero() && mach_angle <= half_pi,
+            "mach_angle must be in (0, pi/2], got {}",
+            show(mach_angle)
+        );
+    }
+}
+
+/// Prandtl-Meyer angle must lie in [0, nu_max).
+pub(crate) fn pm_angle<F: Float>(pm_angle: F, gamma: F) {
+    if cfg!(feature = "strict") {
+        let half_pi = F::from(std::f64::consts::FRAC_PI_2).unwrap();
+        let nu_max = half_pi * (((gamma + F::one()) / (gamma - F::one())).sqrt() - F::one());
+        debug_assert!(
+            pm_angle >= F::zero() && pm_angle < nu_max,
+            "pm_angle must be in [0, {}), got {}",
+            show(nu_max),
+            show(pm_angle)
+        );
+    }
+}
+
+/// Flow deflection angle must lie in [0, pi/2).
+pub(crate) fn deflection<F: Float>(theta: F) {
+    if cfg!(feature = "strict") {
+        let half_pi = F::from(std::f64::consts::FRAC_PI_2).unwrap();
+        debug_assert!(
+            theta >= F::zero() && theta < half_pi,
+            "theta must be in [0, pi/2), got {}",
+            show(theta)
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Critical flow venturis for gas flow metering
+//!
+//! A critical flow venturi, or sonic nozzle, passes a mass flow fixed by its
+//! upstream stagnation state once the throat is choked,
+//! qm = Cd C* A* p0 / sqrt(R T0), following ISO 9300. The critical flow
+//! function C* is that of a perfect gas unless a real-gas value is supplied.
+use crate::{validate, Gas, Viscosity};
+use num::Float;
+
+/// Critical flow function C* of a perfect gas for a given specific heat ratio
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::critical_flow_function;
+///
+/// assert_eq!(critical_flow_function(1.4_f64), 0.6847314563772704);
+/// ```
+pub fn critical_flow_function<F: Float>(gamma: F) -> F {
+    validate::gamma(gamma);
+    let two = F::from(2.).unwrap();
+    (gamma * (two / (gamma + F::one())).powf((gamma + F::one()) / (gamma - F::one()))).sqrt()
+}
+
+/// Discharge coefficient of an ISO 9300 toroidal-throat venturi for a given
+/// throat Reynolds number
+///
+/// Uses Cd = 0.9959 - 2.720 Re^-0.5, with the Reynolds number based on the
+/// throat diameter and the stagnation viscosity.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::iso9300_discharge_coefficient;
+///
+/// assert_eq!(iso9300_discharge_coefficient(1.0e6_f64), 0.99318);
+/// ```
+pub fn iso9300_discharge_coefficient<F: Float>(reynolds: F) -> F {
+    F::from(0.9959).unwrap() - F::from(2.720).unwrap() / reynolds.sqrt()
+}
+
+/// Toroidal-throat critical flow venturi
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{gases, CriticalFlowVenturi, Viscosity};
+///
+/// let venturi = CriticalFlowVenturi::new(5.0e-3_f64);
+/// let air = gases::air();
+/// let mu = Viscosity::sutherland_air();
+/// assert_eq!(venturi.mass_flow(&air, &mu, 5.0e5, 293.15), 0.022967757276743567);
+///
+/// // A real-gas critical flow function replaces the perfect-gas value.
+/// let real = venturi.with_critical_flow_function(0.6862);
+/// assert!(real.mass_flow(&air, &mu, 5.0e5, 293.15) > venturi.mass_flow(&air, &mu, 5.0e5, 293.15));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CriticalFlowVenturi<F> {
+    /// Throat diameter.
+    pub throat_diameter: F,
+    /// Critical flow function, or `None` to use the perfect-gas value.
+    pub critical_flow_function: Option<F>,
+}
+
+impl<F: Float> CriticalFlowVenturi<F> {
+    /// Venturi with a given throat diameter, using the perfect-gas critical
+    /// flow function.
+    pub fn new(throat_diameter: F) -> Self {
+        Self {
+            throat_diameter,
+            critical_flow_function: None,
+        }
+    }
+
+    /// Same venturi with a real-gas critical flow function, e.g. from an
+    /// equation of state evaluated at the stagnation state.
+    pub fn with_critical_flow_function(self, c_star: F) -> Self {
+        Self {
+            critical_flow_function: Some(c_star),
+            ..self
+        }
+    }
+
+    /// Throat area.
+    pub fn throat_area(&self) -> F {
+        F::from(std::f64::consts::FRAC_PI_4).unwrap() * self.throat_diameter.powi(2)
+    }
+
+    /// Mass flow of a choked venturi with ideal discharge coefficient of one.
+    pub fn ideal_mass_flow<G: Gas<F>>(&self, gas: &G, p0: F, t0: F) -> F {
+        let c_star = self
+            .critical_flow_function
+            .unwrap_or_else(|| critical_flow_function(gas.gamma(t0)));
+        c_star * self.throat_area() * p0 / (gas.r() * t0).sqrt()
+    }
+
+    /// Mass flow of the choked venturi for a given upstream stagnation state
+    ///
+    /// The discharge coefficient depends on the throat Reynolds number, which
+    /// in turn depends on the mass flow, so the two are iterated together.
+    pub fn mass_flow<G: Gas<F>>(&self, gas: &G, viscosity: &Viscosity<F>, p0: F, t0: F) -> F {
+        let ideal = self.ideal_mass_flow(gas, p0, t0);
+        let four_over_pi = F::from(4. / std::f64::consts::PI).unwrap();
+        let mut cd = F::one();
+        for _ in 0..10 {
+            let reynolds = four_over_pi * cd * ideal / (self.throat_diameter * viscosity.mu(t0));
+            cd = iso9300_discharge_coefficient(reynolds);
+        }
+        cd * ideal
+    }
+}
@@ -0,0 +1,175 @@
+//! Wave families and sign conventions for 2-D supersonic flow
+//!
+//! Flow angles are measured counterclockwise from the x axis, with the
+//! undisturbed flow running in the positive x direction. Looking downstream,
+//! a left-running wave runs off to the left of the flow (upwards, as produced
+//! by a lower wall) and a right-running wave runs off to the right (downwards,
+//! as produced by an upper wall). Across a left-running wave a compression
+//! turns the flow counterclockwise; across a right-running wave it turns the
+//! flow clockwise.
+//!
+//! The `oblique_*` functions and [`FlowState`](crate::FlowState) work with
+//! unsigned deflections; [`Wave`] applies this convention on top of them so
+//! that compositions of several waves cannot mix conventions.
+use crate::{
+    mach_from_pm_angle, mach_to_mach_angle, mach_to_p_p0, mach_to_pm_angle, validate, ObliqueShock,
+    ShockDetached,
+};
+use num::Float;
+
+/// Family of a wave in 2-D supersonic flow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaveFamily {
+    /// Runs to the left of the flow direction looking downstream.
+    Left,
+    /// Runs to the right of the flow direction looking downstream.
+    Right,
+}
+
+/// Whether a wave compresses or expands the flow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaveKind {
+    /// Oblique shock.
+    Shock,
+    /// Prandtl-Meyer expansion fan.
+    Expansion,
+}
+
+impl WaveFamily {
+    /// Sign of the flow turn produced by a compression of this family.
+    pub fn sign<F: Float>(&self) -> F {
+        match self {
+            WaveFamily::Left => F::one(),
+            WaveFamily::Right => -F::one(),
+        }
+    }
+
+    /// Kind of wave of this family that produces a signed flow turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use comp_flow::{WaveFamily, WaveKind};
+    ///
+    /// // A lower wall turning up into the flow compresses it.
+    /// assert_eq!(WaveFamily::Left.kind(0.1_f64), WaveKind::Shock);
+    /// assert_eq!(WaveFamily::Right.kind(0.1_f64), WaveKind::Expansion);
+    /// ```
+    pub fn kind<F: Float>(&self, turn: F) -> WaveKind {
+        if turn * self.sign() >= F::zero() {
+            WaveKind::Shock
+        } else {
+            WaveKind::Expansion
+        }
+    }
+
+    /// Absolute inclination of a wave of this family, given the upstream flow
+    /// angle and the wave angle relative to the flow (the shock angle or
+    /// Mach angle).
+    pub fn inclination<F: Float>(&self, flow_angle: F, wave_angle: F) -> F {
+        flow_angle + self.sign::<F>() * wave_angle
+    }
+}
+
+/// State change across a single wave of a given family
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{ShockDetached, Wave, WaveFamily, WaveKind};
+///
+/// // Upper wall turning down by 0.1 rad: a right-running shock.
+/// let wave = Wave::new(2.0_f64, 1.4, 0.0, -0.1, WaveFamily::Right)?;
+/// assert_eq!(wave.kind(), WaveKind::Shock);
+/// assert_eq!(wave.flow_angle(), -0.1);
+/// assert!(wave.inclination() < 0.0);
+///
+/// // The same turn on a lower wall expands the flow.
+/// let fan = Wave::new(2.0_f64, 1.4, 0.0, -0.1, WaveFamily::Left)?;
+/// assert_eq!(fan.kind(), WaveKind::Expansion);
+/// assert!(fan.p2_p1() < 1.0);
+/// # Ok::<(), ShockDetached>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wave<F> {
+    family: WaveFamily,
+    kind: WaveKind,
+    mach2: F,
+    flow_angle: F,
+    inclination: F,
+    p2_p1: F,
+    p02_p01: F,
+}
+
+impl<F: Float> Wave<F> {
+    /// Wave of a given family turning flow at `mach` and `flow_angle` by the
+    /// signed angle `turn`.
+    pub fn new(
+        mach: F,
+        gamma: F,
+        flow_angle: F,
+        turn: F,
+        family: WaveFamily,
+    ) -> Result<Self, ShockDetached> {
+        validate::supersonic(mach);
+        validate::gamma(gamma);
+        let kind = family.kind(turn);
+        let (mach2, wave_angle, p2_p1, p02_p01) = match kind {
+            WaveKind::Shock => {
+                let shock = ObliqueShock::new(mach, gamma, turn.abs())?;
+                (shock.mach2(), shock.beta(), shock.p2_p1(), shock.p02_p01())
+            }
+            WaveKind::Expansion => {
+                let nu = mach_to_pm_angle(mach, gamma) + turn.abs();
+                let mach2 = mach_from_pm_angle(nu, gamma);
+                let p2_p1 = mach_to_p_p0(mach2, gamma) / mach_to_p_p0(mach, gamma);
+                (mach2, mach_to_mach_angle(mach), p2_p1, F::one())
+            }
+        };
+        Ok(Self {
+            family,
+            kind,
+            mach2,
+            flow_angle: flow_angle + turn,
+            inclination: family.inclination(flow_angle, wave_angle),
+            p2_p1,
+            p02_p01,
+        })
+    }
+
+    /// Family of the wave.
+    pub fn family(&self) -> WaveFamily {
+        self.family
+    }
+
+    /// Whether the wave is a shock or an expansion.
+    pub fn kind(&self) -> WaveKind {
+        self.kind
+    }
+
+    /// Mach number after the wave.
+    pub fn mach2(&self) -> F {
+        self.mach2
+    }
+
+    /// Flow angle after the wave.
+    pub fn flow_angle(&self) -> F {
+        self.flow_angle
+    }
+
+    /// Absolute inclination of the shock, or of the leading Mach line of an
+    /// expansion fan.
+    pub fn inclination(&self) -> F {
+        self.inclination
+    }
+
+    /// Static pressure ratio across the wave.
+    pub fn p2_p1(&self) -> F {
+        self.p2_p1
+    }
+
+    /// Stagnation pressure ratio across the wave; one for an expansion.
+    pub fn p02_p01(&self) -> F {
+        self.p02_p01
+    }
+}
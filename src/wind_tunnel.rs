@@ -0,0 +1,106 @@
+//! Starting and running a supersonic wind tunnel with a second throat
+//!
+//! While the tunnel starts, a normal shock passes through the test section
+//! and the second throat must swallow the subsonic flow behind it, whose
+//! sonic area is larger than the first throat by the stagnation pressure loss
+//! of the shock. Once started, the flow compresses supersonically to the
+//! second throat and the shock can sit there, where the Mach number and the
+//! loss are lowest. Area ratios are relative to the first (nozzle) throat and
+//! pressure ratios are supply stagnation pressure to diffuser back pressure,
+//! taking the diffuser to recover the stagnation pressure behind the shock.
+use crate::{mach_from_a_ac, normal_p02_p01, validate};
+use num::Float;
+
+/// Smallest second throat to first throat area ratio that lets the tunnel
+/// start at a test section Mach number
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::second_throat_area_ratio;
+///
+/// assert_eq!(second_throat_area_ratio(2.0_f64, 1.4), 1.387205242731861);
+/// ```
+pub fn second_throat_area_ratio<F: Float>(mach: F, gamma: F) -> F {
+    normal_p02_p01(mach, gamma).recip()
+}
+
+/// Supply to back pressure ratio needed to start the tunnel, with a normal
+/// shock standing in the test section
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{second_throat_area_ratio, starting_pressure_ratio};
+///
+/// assert_eq!(starting_pressure_ratio(2.0_f64, 1.4), second_throat_area_ratio(2.0, 1.4));
+/// ```
+pub fn starting_pressure_ratio<F: Float>(mach: F, gamma: F) -> F {
+    normal_p02_p01(mach, gamma).recip()
+}
+
+/// Supply to back pressure ratio needed to keep a started tunnel running,
+/// with the normal shock at a second throat of area ratio `a_t2_a_t1`
+///
+/// The second throat must be at least [`second_throat_area_ratio`] for the
+/// tunnel to have started.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{running_pressure_ratio, second_throat_area_ratio, starting_pressure_ratio};
+///
+/// let a_t2_a_t1 = second_throat_area_ratio(2.0_f64, 1.4);
+/// assert_eq!(running_pressure_ratio(a_t2_a_t1, 1.4), 1.1986639041768072);
+/// assert!(running_pressure_ratio(a_t2_a_t1, 1.4) < starting_pressure_ratio(2.0, 1.4));
+/// ```
+pub fn running_pressure_ratio<F: Float>(a_t2_a_t1: F, gamma: F) -> F {
+    validate::area_ratio(a_t2_a_t1);
+    validate::gamma(gamma);
+    let throat_mach = mach_from_a_ac(a_t2_a_t1, gamma, true);
+    if throat_mach <= F::one() {
+        F::one()
+    } else {
+        normal_p02_p01(throat_mach, gamma).recip()
+    }
+}
+
+/// Operating state of a tunnel at a given pressure ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TunnelState {
+    /// The tunnel starts from rest.
+    Starts,
+    /// The tunnel keeps running once started but cannot start from rest.
+    RunsIfStarted,
+    /// The tunnel cannot run supersonically.
+    Unstarted,
+}
+
+/// Operating state of a tunnel with a test section Mach number, second
+/// throat area ratio `a_t2_a_t1` and supply to back pressure ratio `p0_pb`
+///
+/// A second throat smaller than [`second_throat_area_ratio`] chokes the
+/// starting flow and the tunnel never starts, whatever the pressure ratio.
+///
+/// # Examples
+///
+/// ```
+/// use comp_flow::{tunnel_state, TunnelState};
+///
+/// assert_eq!(tunnel_state(2.0_f64, 1.4, 1.45, 1.5), TunnelState::Starts);
+/// assert_eq!(tunnel_state(2.0_f64, 1.4, 1.45, 1.3), TunnelState::RunsIfStarted);
+/// assert_eq!(tunnel_state(2.0_f64, 1.4, 1.45, 1.01), TunnelState::Unstarted);
+/// assert_eq!(tunnel_state(2.0_f64, 1.4, 1.2, 3.0), TunnelState::Unstarted);
+/// ```
+pub fn tunnel_state<F: Float>(mach: F, gamma: F, a_t2_a_t1: F, p0_pb: F) -> TunnelState {
+    validate::supersonic(mach);
+    if a_t2_a_t1 < second_throat_area_ratio(mach, gamma) {
+        TunnelState::Unstarted
+    } else if p0_pb >= starting_pressure_ratio(mach, gamma) {
+        TunnelState::Starts
+    } else if p0_pb >= running_pressure_ratio(a_t2_a_t1, gamma) {
+        TunnelState::RunsIfStarted
+    } else {
+        TunnelState::Unstarted
+    }
+}
@@ -0,0 +1,566 @@
+//! Without the `strict` feature every public function must be total over
+//! finite inputs. These sweeps call the public API over grids of finite
+//! values, valid or not, and fail on any panic.
+#![cfg(not(feature = "strict"))]
+
+use comp_flow::*;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Values for functions of one or two arguments.
+const WIDE: [f64; 12] = [
+    -2.0, -1e-3, 0.0, 1e-9, 0.3, 1.0, 1.001, 1.4, 2.5, 30.0, 1e8, 1e300,
+];
+/// Values for functions of three or more arguments.
+const NARROW: [f64; 7] = [-1.0, 0.0, 0.5, 1.0, 1.4, 3.0, 1e8];
+/// Values for sweeps that run iterative or nested solves.
+const SHORT: [f64; 5] = [-1.0, 0.0, 0.5, 1.4, 3.0];
+
+/// Calls that panicked, recorded with their arguments.
+#[derive(Default)]
+struct Sweep {
+    failures: Vec<String>,
+}
+
+impl Sweep {
+    fn check(&mut self, call: &str, args: &[f64], f: impl FnOnce()) {
+        if panic::catch_unwind(AssertUnwindSafe(f)).is_err() {
+            self.failures.push(format!("{call} with {args:?}"));
+        }
+    }
+
+    fn assert_total(self) {
+        assert!(
+            self.failures.is_empty(),
+            "{} calls panicked:\n{}",
+            self.failures.len(),
+            self.failures.join("\n")
+        );
+    }
+}
+
+/// Call an expression for every combination of its arguments from a grid.
+macro_rules! sweep {
+    ($s:ident, $grid:expr, |$a:ident| $body:expr) => {
+        for &$a in &$grid {
+            $s.check(stringify!($body), &[$a], || {
+                let _ = $body;
+            });
+        }
+    };
+    ($s:ident, $grid:expr, |$a:ident, $b:ident| $body:expr) => {
+        for &$a in &$grid {
+            for &$b in &$grid {
+                $s.check(stringify!($body), &[$a, $b], || {
+                    let _ = $body;
+                });
+            }
+        }
+    };
+    ($s:ident, $grid:expr, |$a:ident, $b:ident, $c:ident| $body:expr) => {
+        for &$a in &$grid {
+            for &$b in &$grid {
+                for &$c in &$grid {
+                    $s.check(stringify!($body), &[$a, $b, $c], || {
+                        let _ = $body;
+                    });
+                }
+            }
+        }
+    };
+    ($s:ident, $grid:expr, |$a:ident, $b:ident, $c:ident, $d:ident| $body:expr) => {
+        for &$a in &$grid {
+            for &$b in &$grid {
+                for &$c in &$grid {
+                    for &$d in &$grid {
+                        $s.check(stringify!($body), &[$a, $b, $c, $d], || {
+                            let _ = $body;
+                        });
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[test]
+fn isentropic_relations_are_total() {
+    let mut s = Sweep::default();
+    sweep!(s, WIDE, |a| mach_to_mach_angle(a));
+    sweep!(s, WIDE, |a| mach_from_mach_angle(a));
+    sweep!(s, WIDE, |a| sonic_t_t0(a));
+    sweep!(s, WIDE, |a| sonic_p_p0(a));
+    sweep!(s, WIDE, |a| sonic_rho_rho0(a));
+    sweep!(s, WIDE, |a| sonic_a_a0(a));
+    sweep!(s, WIDE, |a, b| mach_to_pm_angle(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_t_t0(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_p_p0(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_rho_rho0(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_a_ac(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_v_cpt0(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_mcpt0_ap0(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_mcpt0_ap(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_pitot_p02_p1(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_mach_star(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_crocco(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_t_tstar(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_p_pstar(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_rho_rhostar(a, b));
+    sweep!(s, WIDE, |a, b| mach_to_a_astar(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_pm_angle(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_t_t0(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_p_p0(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_rho_rho0(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_a_ac(a, b, false));
+    sweep!(s, WIDE, |a, b| mach_from_a_ac(a, b, true));
+    sweep!(s, WIDE, |a, b| mach_from_pitot_p02_p1(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_pitot(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_mach_star(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_crocco(a, b));
+    sweep!(s, WIDE, |a, b| Isentropic::new(a, b).quantities());
+    for name in ["M", "T/T0", "p/p0", "rho/rho0", "A/A*", "nu", "beta"] {
+        sweep!(s, WIDE, |a| IsentropicQuantity::from_name(name, a));
+    }
+    sweep!(s, NARROW, |a, b, c| isentropic_lookup(
+        IsentropicQuantity::AAc(a),
+        IsentropicQuantity::PP0(b),
+        c,
+        0.05
+    ));
+    sweep!(s, NARROW, |a, b, c| isentropic_lookup(
+        IsentropicQuantity::TT0(a),
+        IsentropicQuantity::Mach(b),
+        c,
+        0.05
+    ));
+    sweep!(s, NARROW, |a, b, c| isentropic_lookup(
+        IsentropicQuantity::PmAngle(a),
+        IsentropicQuantity::RhoRho0(b),
+        c,
+        0.05
+    ));
+    s.assert_total();
+}
+
+#[test]
+fn shock_relations_are_total() {
+    let mut s = Sweep::default();
+    sweep!(s, WIDE, |a, b| normal_mach2(a, b));
+    sweep!(s, WIDE, |a, b| normal_p02_p01(a, b));
+    sweep!(s, WIDE, |a, b| normal_p2_p1(a, b));
+    sweep!(s, WIDE, |a, b| normal_rho2_rho1(a, b));
+    sweep!(s, WIDE, |a, b| normal_t2_t1(a, b));
+    sweep!(s, WIDE, |a, b| normal_a2_a1(a, b));
+    sweep!(s, WIDE, |a, b| normal_mach_star2(a, b));
+    sweep!(s, WIDE, |a, b| normal_ds_r(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_normal_p2_p1(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_normal_rho2_rho1(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_normal_t2_t1(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_normal_p02_p01(a, b));
+    sweep!(s, WIDE, |a, b| NormalShock::new(a, b).quantities());
+    sweep!(s, NARROW, |a, b, c, d| normal_shock_primitive(a, b, c, d));
+    sweep!(s, WIDE, |a, b| oblique_beta_max(a, b));
+    sweep!(s, WIDE, |a, b| oblique_theta_max(a, b));
+    sweep!(s, NARROW, |a, b, c| oblique_beta(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_beta_strong(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_beta_checked(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_beta_strong_checked(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_theta(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_mach_from_beta_theta(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_beta_from_p2_p1(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_theta_from_p2_p1(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_mach2(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_p02_p01(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_p2_p1(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_rho2_rho1(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_t2_t1(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_a2_a1(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_ds_r(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_loss_coefficient(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_mach2_strong(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_p02_p01_strong(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_p2_p1_strong(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_rho2_rho1_strong(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_t2_t1_strong(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_a2_a1_strong(a, b, c));
+    sweep!(s, NARROW, |a, b, c| oblique_reflection(a, b, c));
+    sweep!(s, NARROW, |a, b, c| ObliqueShock::new(a, b, c)
+        .map(|x| x.quantities()));
+    sweep!(s, NARROW, |a, b, c| ObliqueShock::strong(a, b, c)
+        .map(|x| x.quantities()));
+    sweep!(s, NARROW, |a, b, c| ObliqueShock::from_beta(a, b, c)
+        .quantities());
+    sweep!(s, NARROW, |a, b, c, d| oblique_shock_primitive(
+        a,
+        [b, c],
+        d,
+        [c, d],
+        1.4
+    ));
+    sweep!(s, NARROW, |a, b, c, d| oblique_from_velocities(
+        [a, b],
+        [c, d],
+        a,
+        1.4
+    ));
+    sweep!(s, NARROW, |a, b, c| expansion_mach2(a, b, c));
+    sweep!(s, NARROW, |a, b, c| expansion_p2_p1(a, b, c));
+    sweep!(s, NARROW, |a, b, c| expansion_t2_t1(a, b, c));
+    sweep!(s, NARROW, |a, b, c| expansion_rho2_rho1(a, b, c));
+    sweep!(s, NARROW, |a, b, c| expansion_theta_from_p2_p1(a, b, c));
+    sweep!(s, NARROW, |a, b, c, d| expansion_fan_ray(a, b, c, d));
+    for family in [WaveFamily::Left, WaveFamily::Right] {
+        sweep!(s, NARROW, |a, b, c, d| Wave::new(a, b, c, d, family));
+    }
+    s.assert_total();
+}
+
+#[test]
+fn wave_systems_are_total() {
+    let mut s = Sweep::default();
+    for n in [0, 1, 7] {
+        sweep!(s, WIDE, |a, b| shock_polar(a, b, n));
+        sweep!(s, WIDE, |a, b| oswatitsch_inlet(a, b, n));
+    }
+    sweep!(s, SHORT, |a, b, c, d| {
+        let polar = ShockPolar::from_state(a, b, c, d);
+        (polar.pressure(a), polar.deflect(d))
+    });
+    sweep!(s, SHORT, |a, b, c, d| polar_intersections(
+        &ShockPolar::new(a, b),
+        &ShockPolar::from_state(c, b, d, d)
+    ));
+    sweep!(s, SHORT, |a, b, c, d| edney_interaction(a, b, c, d));
+    sweep!(s, NARROW, |a, b, c| ramp_compression(a, b, &[c, c]));
+    sweep!(s, SHORT, |a, b, c, d| track_duct(
+        a,
+        1.4,
+        b,
+        &[(c, d), (d, c)],
+        3.0
+    ));
+    sweep!(s, SHORT, |a, b, c, d| {
+        let state = FlowState::new(a, b).through_normal_shock().expand_pm(c);
+        (state.to_area_ratio(d).quantities(), state.turn_oblique(d))
+    });
+    sweep!(s, SHORT, |a, b, c, d| {
+        let traced = FlowState::new(a, b).traced().expand_pm(c).to_area_ratio(d);
+        traced
+            .turn_oblique(c)
+            .map(|t| t.through_normal_shock().to_csv())
+    });
+    sweep!(s, NARROW, |a, b, c, d| ShockInterface::new(a, b, c, d)
+        .reflected_p_ratio());
+    sweep!(s, NARROW, |a, b, c| hugoniot_p2_p1(a, b)
+        + rayleigh_line_p2_p1(a, b, c));
+    for n in [0, 1, 7] {
+        sweep!(s, NARROW, |a, b, c| (
+            hugoniot_curve(a, b, n),
+            rayleigh_line(a, b, c, n)
+        ));
+    }
+    s.assert_total();
+}
+
+#[test]
+fn inlets_and_nozzles_are_total() {
+    let mut s = Sweep::default();
+    sweep!(s, WIDE, |a, b| kantrowitz_limit(a, b));
+    sweep!(s, NARROW, |a, b, c| self_starts(a, b, c));
+    sweep!(s, NARROW, |a, b, c, d| {
+        let inlet = Inlet::new(a, b);
+        (
+            inlet.throat_mach(c, d),
+            inlet.max_back_pressure(c, d),
+            inlet.unstart_margin(c, d, a),
+            inlet.self_starts(c, d),
+        )
+    });
+    sweep!(s, SHORT, |a, b, c| unstart_margin_map(
+        &Inlet::new(a, b),
+        &[a, c],
+        &[b, c],
+        c
+    ));
+    for recovery in [
+        InletRecovery::MilE5008B,
+        InletRecovery::NormalShock,
+        InletRecovery::Oswatitsch { shocks: 2 },
+    ] {
+        sweep!(s, WIDE, |a, b| recovery.recovery(a, b));
+    }
+    sweep!(s, WIDE, |a, b| second_throat_area_ratio(a, b));
+    sweep!(s, WIDE, |a, b| starting_pressure_ratio(a, b));
+    sweep!(s, WIDE, |a, b| running_pressure_ratio(a, b));
+    sweep!(s, NARROW, |a, b, c, d| tunnel_state(a, b, c, d));
+    sweep!(s, NARROW, |a, b, c| thrust_coefficient(a, b, c));
+    sweep!(s, NARROW, |a, b, c| aerospike_thrust_coefficient(a, b, c));
+    for n in [0, 1, 7] {
+        sweep!(s, WIDE, |a, b| aerospike_contour(a, b, n));
+    }
+    sweep!(s, SHORT, |a, b, c| altitude_performance(
+        a,
+        288.0,
+        b,
+        c,
+        1.4,
+        287.0,
+        &[a, b, c, 1e5]
+    ));
+    sweep!(s, SHORT, |a, b, c| throttle_map(
+        FeedRestriction::Orifice { cd: a, area: b },
+        1000.0,
+        c,
+        b,
+        c,
+        1.4,
+        a,
+        &[a, b, c]
+    ));
+    sweep!(s, SHORT, |a, b, c| throttle_map(
+        FeedRestriction::CavitatingVenturi {
+            cd: a,
+            area: b,
+            vapour_pressure: c,
+            recovery: b,
+        },
+        1000.0,
+        c,
+        b,
+        c,
+        1.4,
+        a,
+        &[a, b, c]
+    ));
+    sweep!(s, SHORT, |a, b, c, d| ColdGasThruster {
+        volume: a,
+        throat_area: b,
+        mach_exit: c,
+        regulator: Some(d),
+    }
+    .blowdown(&gases::nitrogen(), a, b, c, d, c));
+    sweep!(s, WIDE, |a| critical_flow_function(a));
+    sweep!(s, WIDE, |a| iso9300_discharge_coefficient(a));
+    sweep!(s, NARROW, |a, b, c| {
+        let venturi = CriticalFlowVenturi::new(a);
+        (
+            venturi.ideal_mass_flow(&gases::air(), b, c),
+            venturi.mass_flow(&gases::air(), &Viscosity::sutherland_air(), b, c),
+        )
+    });
+    s.assert_total();
+}
+
+#[test]
+fn flight_and_heating_relations_are_total() {
+    let mut s = Sweep::default();
+    sweep!(s, WIDE, |a| standard_atmosphere(a));
+    sweep!(s, WIDE, |a| pressure_altitude(a));
+    sweep!(s, WIDE, |a| geopotential_altitude(a));
+    sweep!(s, WIDE, |a, b| {
+        let air = AirData::new(a, b);
+        (
+            air.mach_from_cas(b),
+            air.cas_from_mach(b),
+            air.mach_from_tas(b),
+            air.tas_from_cas(b),
+            air.cas_from_tas(b),
+            air.tas_from_eas(b),
+        )
+    });
+    sweep!(s, NARROW, |a, b, c| airdata_uncertainty_budget(
+        &[(a, b), (b, c)],
+        a,
+        c
+    ));
+    sweep!(s, SHORT, |a, b, c| trajectory(&[(a, b), (b, c), (c, a)], c));
+    sweep!(s, SHORT, |a, b, c| envelope_sweep(&[a, b], &[c, b], |f| f
+        .mach));
+    for recovery in [
+        RecoveryFactor::Laminar,
+        RecoveryFactor::Turbulent,
+        RecoveryFactor::Fixed(0.9),
+    ] {
+        sweep!(s, SHORT, |a, b, c| thermal_placard(&[a, b], c, recovery, b));
+        sweep!(s, NARROW, |a, b, c| adiabatic_wall_temperature(
+            a, b, c, recovery, 0.72
+        ));
+        sweep!(s, NARROW, |a, b, c| probe_t0(a, b, c, recovery, 0.72));
+        sweep!(s, NARROW, |a, b, c| probe_t(a, b, c, recovery, 0.72));
+        sweep!(s, NARROW, |a, b, c| mach_from_probe_tr_t(a, b, recovery, c));
+        sweep!(s, NARROW, |a, b, c, d| reference_temperature(
+            a, b, c, d, recovery, 0.72
+        ));
+    }
+    sweep!(s, NARROW, |a, b, c| sutton_graves_heat_flux(a, b, c));
+    sweep!(s, SHORT, |a, b, c, d| fay_riddell_heat_flux(
+        &gases::air(),
+        &Transport::air(),
+        a,
+        b,
+        c,
+        d,
+        c
+    ));
+    sweep!(s, NARROW, |a, b| mach_from_wave_angle(a, b));
+    sweep!(s, SHORT, |a, b, c, d| mach_from_wedge_shock(
+        a, b, c, d, 1.4
+    ));
+    sweep!(s, NARROW, |a, b, c| post_shock_dynamic_pressure(a, b, c));
+    sweep!(s, NARROW, |a, b, c| decelerator_drag(a, b, c, b, a));
+    sweep!(s, WIDE, |a| cp_newtonian(a));
+    sweep!(s, NARROW, |a, b, c| cp_modified_newtonian(a, b, c));
+    sweep!(s, NARROW, |a, b, c| newtonian_pm_cp(a, b, c));
+    sweep!(s, NARROW, |a, b, c, d| sphere_cone_cp(a, b, c, d));
+    sweep!(s, NARROW, |a, b, c| hypersonic_wedge_beta(a, b, c));
+    sweep!(s, NARROW, |a, b, c| hypersonic_wedge_cp(a, b, c));
+    sweep!(s, NARROW, |a, b, c| hypersonic_cone_cp(a, b, c));
+    sweep!(s, NARROW, |a, b, c| hypersonic_limit_p2_p1(a, b, c));
+    sweep!(s, NARROW, |a, b, c| hypersonic_limit_t2_t1(a, b, c));
+    sweep!(s, WIDE, |a| hypersonic_limit_rho2_rho1(a));
+    for body in [BluntBody::Sphere, BluntBody::Cylinder] {
+        sweep!(s, WIDE, |a, b| (
+            billig_standoff(a, body),
+            billig_shock_radius(a, body),
+            billig_shock_shape(a, body, b)
+        ));
+    }
+    for correction in [
+        CompressibilityCorrection::PrandtlGlauert,
+        CompressibilityCorrection::KarmanTsien,
+        CompressibilityCorrection::Laitone,
+    ] {
+        sweep!(s, WIDE, |a, b| critical_mach(a, b, correction));
+    }
+    sweep!(s, WIDE, |a, b| cp_star(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_cp_star(a, b));
+    sweep!(s, NARROW, |a, b, c| cp_from_local_mach(a, b, c));
+    sweep!(s, NARROW, |a, b, c| local_mach_from_cp(a, b, c));
+    sweep!(s, WIDE, |a, b| bernoulli_dynamic_pressure(a, b));
+    sweep!(s, WIDE, |a, b| incompressible_pitot_velocity(a, b));
+    sweep!(s, WIDE, |a, b| impact_pressure_factor(a, b));
+    sweep!(s, WIDE, |a, b| impact_pressure_ratio(a, b));
+    sweep!(s, WIDE, |a, b| mach_from_impact_pressure_ratio(a, b));
+    sweep!(s, WIDE, |a, b| pitot_velocity_factor(a, b));
+    sweep!(s, WIDE, |a, b| incompressible_crossover_mach(a, b));
+    sweep!(s, NARROW, |a, b, c| speed_of_sound(a, b, c));
+    sweep!(s, WIDE, |a, b| velocity_from_mach(a, b));
+    sweep!(s, NARROW, |a, b, c| dynamic_pressure(a, b, c));
+    sweep!(s, NARROW, |a, b, c| impact_pressure(a, b, c));
+    sweep!(s, NARROW, |a, b, c| probe_stagnation_point(
+        &gases::air(),
+        a,
+        b,
+        c
+    ));
+    s.assert_total();
+}
+
+#[test]
+fn gas_models_and_solvers_are_total() {
+    let mut s = Sweep::default();
+    sweep!(s, WIDE, |a| gases::saturation_vapour_pressure(a));
+    sweep!(s, WIDE, |a| gases::humid_air_from_specific_humidity(a));
+    sweep!(s, NARROW, |a, b, c| gases::humid_air(a, b, c));
+    sweep!(s, NARROW, |a, b| GasMixture::from_mole_fractions(&[
+        (CaloricallyPerfect::new(a, b), a),
+        (gases::helium(), b)
+    ])
+    .molar_mass());
+    sweep!(s, NARROW, |a, b, c| {
+        let gas = HarmonicOscillator::new(a, b, c);
+        (
+            temperature_from_enthalpy(&gas, a, b),
+            thermally_perfect_t0(&gas, b, c),
+            thermally_perfect_p_p0(&gas, b, c),
+            ThermallyPerfectNormalShock::new(&gas, b, c),
+        )
+    });
+    sweep!(s, NARROW, |a, b, c| {
+        let gas = CaloricallyPerfect::new(a, 287.0);
+        let state = PrimitiveState::new(b, c, a);
+        (
+            euler_flux(&gas, [a, b, c]),
+            hll_flux(&gas, [a, b, c], [c, b, a]),
+            hllc_flux(&gas, [a, b, c], [c, b, a]),
+            PrimitiveState::from_conservative(&gas, [a, b, c]).mach(&gas),
+            inflow_ghost_state(&gas, state, a, b),
+            outflow_ghost_state(&gas, state, c),
+            freestream_state(&gas, a, b, c, a),
+        )
+    });
+    sweep!(s, NARROW, |a, b, c| ReferenceScales::from_freestream(
+        &gases::air(),
+        a,
+        b,
+        c
+    )
+    .to_nondimensional(PrimitiveState::new(a, b, c)));
+    sweep!(s, NARROW, |a, b, c| Traverse::new(
+        TraverseGeometry::Axisymmetric,
+        &[0.0, a, b],
+        &[c, b, a],
+        &[b, b, c],
+        &[a, c, c],
+        1.4,
+        287.0
+    )
+    .average(Averaging::StreamThrust));
+    for method in [
+        Averaging::Area,
+        Averaging::Mass,
+        Averaging::Work,
+        Averaging::Entropy,
+    ] {
+        sweep!(s, SHORT, |a, b, c| Traverse::new(
+            TraverseGeometry::Planar,
+            &[0.0, a, b],
+            &[c, b, a],
+            &[b, b, c],
+            &[a, c, c],
+            1.4,
+            287.0
+        )
+        .average(method));
+    }
+    sweep!(s, SHORT, |a, b, c| locate_shocks(
+        &[a, b, c],
+        &[c, b, a],
+        &[b, c, a],
+        1.4,
+        a
+    ));
+    sweep!(s, SHORT, |a, b, c| isentropic_deviation_p_t(
+        &[a, b],
+        &[b, c],
+        a,
+        b,
+        c
+    ));
+    sweep!(s, SHORT, |a, b, c| isentropic_deviation_p_rho(
+        &[a, b],
+        &[b, c],
+        a,
+        b,
+        c
+    ));
+    sweep!(s, SHORT, |a, b, c| non_isentropic_regions(&[a, b, c], b));
+    for ends in [
+        DuctEnds::OpenOpen,
+        DuctEnds::ClosedClosed,
+        DuctEnds::OpenClosed,
+    ] {
+        sweep!(s, NARROW, |a, b, c| duct_mode_frequency(1, a, b, c, ends));
+    }
+    sweep!(s, NARROW, |a, b, c| acoustic_impedance(a, b, c));
+    sweep!(s, NARROW, |a, b, c| acoustic_p_from_u(a, b, c));
+    sweep!(s, NARROW, |a, b, c| acoustic_u_from_p(a, b, c));
+    sweep!(s, WIDE, |a, b| acoustic_rho_from_p(a, b));
+    sweep!(s, WIDE, |a, b| acoustic_t_from_p(a, b));
+    sweep!(s, NARROW, |a, b, c, d| acoustic_reflection(a, b, c, d));
+    sweep!(s, NARROW, |a, b, c, d| acoustic_transmission(a, b, c, d));
+    sweep!(s, NARROW, |a, b, c, d| helmholtz_frequency(a, b, c, d));
+    sweep!(s, WIDE, |a, b| nozzle_admittance(a, b));
+    sweep!(s, WIDE, |a, b| nozzle_reflection(a, b));
+    sweep!(s, WIDE, |a| accuracy_manifest(a, 3));
+    s.assert_total();
+}